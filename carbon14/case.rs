@@ -0,0 +1,231 @@
+use std::time::SystemTime;
+
+use iocore::PathDateTime;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::dfxml::Creator;
+use crate::{Algorithm, Error, RawDigest};
+
+/// prefixes of the CASE and UCO ontologies the nodes are written in,
+/// and of `kb:` which the nodes of a bundle are identified by
+pub fn context() -> Value {
+    json!({
+        "kb": "http://example.org/kb/",
+        "case-investigation": "https://ontology.caseontology.org/case/investigation/",
+        "uco-action": "https://ontology.unifiedcyberontology.org/uco/action/",
+        "uco-core": "https://ontology.unifiedcyberontology.org/uco/core/",
+        "uco-observable": "https://ontology.unifiedcyberontology.org/uco/observable/",
+        "uco-tool": "https://ontology.unifiedcyberontology.org/uco/tool/",
+        "uco-types": "https://ontology.unifiedcyberontology.org/uco/types/",
+        "uco-vocabulary": "https://ontology.unifiedcyberontology.org/uco/vocabulary/",
+        "xsd": "http://www.w3.org/2001/XMLSchema#",
+    })
+}
+
+/// a CASE JSON-LD document of a run of carbon14, i.e.: a `@graph` of
+/// the carbon14 `uco-tool:Tool`, an `uco-observable:File` per table and
+/// the `case-investigation:InvestigativeAction` of the run which has
+/// every file as its result
+#[derive(Debug, Clone)]
+pub struct Bundle {
+    seed: [u8; 32],
+    creator: Creator,
+}
+
+impl Bundle {
+    /// a bundle whose `@id`s are unique to the run, i.e.: derived from
+    /// the host, process, command line and time the run starts at
+    pub fn new(creator: Creator) -> Bundle {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let mut seed = blake3::Hasher::new();
+        seed.update(creator.host.as_bytes());
+        seed.update(&std::process::id().to_be_bytes());
+        seed.update(creator.command_line.as_bytes());
+        seed.update(&now.to_be_bytes());
+        Bundle {
+            seed: *seed.finalize().as_bytes(),
+            creator,
+        }
+    }
+
+    /// the start of the document up to the node of the first file,
+    /// i.e.: its `@context` and the node of the tool
+    pub fn header(&self) -> String {
+        let tool = json!({
+            "@id": self.id("tool", "carbon14"),
+            "@type": "uco-tool:Tool",
+            "uco-core:name": "carbon14",
+            "uco-tool:version": self.creator.version,
+        });
+        format!("{{\"@context\":{},\"@graph\":[\n{}", context(), tool)
+    }
+
+    /// the node of the `index`th file of the run, preceded by the
+    /// separator from the previous node, see [`file`]
+    pub fn file(&self, index: usize, document: impl Serialize) -> Result<String, Error> {
+        let node = file(&self.file_id(index), document)?;
+        Ok(format!(",\n{}", node))
+    }
+
+    /// the end of the document after `count` files, i.e.: the node of
+    /// the action which ends as it is written
+    pub fn footer(&self, count: usize) -> String {
+        let action = json!({
+            "@id": self.id("investigative-action", "carbon14"),
+            "@type": "case-investigation:InvestigativeAction",
+            "uco-core:name": "carbon14",
+            "uco-core:description": self.creator.command_line,
+            "uco-action:startTime": typed("xsd:dateTime", self.creator.start_time.to_string()),
+            "uco-action:endTime":
+                typed("xsd:dateTime", PathDateTime::from(SystemTime::now()).to_string()),
+            "uco-action:instrument": { "@id": self.id("tool", "carbon14") },
+            "uco-action:result": (0..count)
+                .map(|index| json!({ "@id": self.file_id(index) }))
+                .collect::<Vec<_>>(),
+        });
+        format!(",\n{}\n]}}\n", action)
+    }
+
+    /// `@id` of a node of the bundle, which is the same for the same
+    /// `kind` and `name`
+    fn id(&self, kind: &str, name: &str) -> String {
+        let mut hasher = blake3::Hasher::new_keyed(&self.seed);
+        hasher.update(format!("{}/{}", kind, name).as_bytes());
+        format!("kb:{}-{}", kind, uuid(hasher.finalize().as_bytes()))
+    }
+
+    /// `@id` of the `index`th file, derived from the bundle so that the
+    /// action can refer to every file without keeping them around
+    fn file_id(&self, index: usize) -> String {
+        self.id("file", &index.to_string())
+    }
+}
+
+/// the `uco-observable:File` of a [`crate::TableV1`], with a
+/// `FileFacet` of its name, size and timestamps, and a
+/// `ContentDataFacet` with a `uco-types:Hash` of each algorithm, except
+/// the fuzzy ones whose digests aren't hex. Algorithms in the
+/// `HashNameVocab` of UCO are named as such, e.g.: `SHA256`, others
+/// by their name in carbon14. Tables of a byte range don't digest the
+/// content of their file and are refused.
+pub fn file(id: &str, document: impl Serialize) -> Result<Value, Error> {
+    let document = serde_json::to_value(document)
+        .map_err(|e| Error::Error(format!("encoding a CASE file: {}", e)))?;
+    let field = |name: &str| document.get(name).and_then(Value::as_str);
+    let digests = Algorithm::every()
+        .filter(|algorithm| !Algorithm::FUZZY.contains(algorithm))
+        .filter_map(|algorithm| Some((algorithm, field(algorithm.name())?)))
+        .collect::<Vec<_>>();
+    if field("filename").is_none() && digests.is_empty() {
+        return Err(Error::Error("only tables can be written as CASE files".to_string()));
+    }
+    if document.get("range").is_some() {
+        return Err(Error::Error(
+            "only digests of whole files can be written as CASE content hashes".to_string(),
+        ));
+    }
+    let node_id = |kind: &str| format!("{}-{}", id, kind);
+    let size = document
+        .get("size")
+        .and_then(Value::as_u64)
+        .map(|size| typed("xsd:integer", size.to_string()));
+    let mut file_facet = json!({
+        "@id": node_id("file-facet"),
+        "@type": "uco-observable:FileFacet",
+    });
+    if let Some(filename) = field("filename") {
+        let name = std::path::Path::new(filename)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or(filename.to_string());
+        file_facet["uco-observable:fileName"] = json!(name);
+        file_facet["uco-observable:filePath"] = json!(filename);
+    }
+    if let Some(size) = &size {
+        file_facet["uco-observable:sizeInBytes"] = size.clone();
+    }
+    for (name, property) in [
+        ("modified", "uco-observable:modifiedTime"),
+        ("accessed", "uco-observable:accessedTime"),
+        ("created", "uco-observable:observableCreatedTime"),
+    ] {
+        if let Some(time) = field(name) {
+            file_facet[property] = typed("xsd:dateTime", time);
+        }
+    }
+    let hashes = digests
+        .iter()
+        .map(|(algorithm, digest)| {
+            let digest = RawDigest::from_hex(Some(*algorithm), digest).map_err(|_| {
+                Error::Error(format!(
+                    "CASE hashes take hex digests, not {:#?}, try --encoding hex",
+                    digest
+                ))
+            })?;
+            let method = match hash_name(*algorithm) {
+                Some(name) => typed("uco-vocabulary:HashNameVocab", name),
+                None => json!(algorithm.name()),
+            };
+            Ok(json!({
+                "@id": node_id(&format!("hash-{}", algorithm.name())),
+                "@type": "uco-types:Hash",
+                "uco-types:hashMethod": method,
+                "uco-types:hashValue": typed("xsd:hexBinary", hex::encode(digest.bytes())),
+            }))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let mut content_data_facet = json!({
+        "@id": node_id("content-data-facet"),
+        "@type": "uco-observable:ContentDataFacet",
+        "uco-observable:hash": hashes,
+    });
+    if let Some(size) = size {
+        content_data_facet["uco-observable:sizeInBytes"] = size;
+    }
+    let mut node = json!({
+        "@id": id,
+        "@type": "uco-observable:File",
+        "uco-core:hasFacet": [file_facet, content_data_facet],
+    });
+    if document.get("unstable") == Some(&Value::Bool(true)) {
+        node["uco-core:description"] = json!("changed while being digested");
+    }
+    Ok(node)
+}
+
+/// name of the algorithm in the `HashNameVocab` of UCO
+pub fn hash_name(algorithm: Algorithm) -> Option<&'static str> {
+    match algorithm {
+        Algorithm::Md5 => Some("MD5"),
+        Algorithm::Sha1 => Some("SHA1"),
+        Algorithm::Sha224 => Some("SHA224"),
+        Algorithm::Sha256 => Some("SHA256"),
+        Algorithm::Sha384 => Some("SHA384"),
+        Algorithm::Sha512 => Some("SHA512"),
+        Algorithm::Sha3_224 => Some("SHA3-224"),
+        Algorithm::Sha3_256 => Some("SHA3-256"),
+        Algorithm::Sha3_384 => Some("SHA3-384"),
+        Algorithm::Sha3_512 => Some("SHA3-512"),
+        _ => None,
+    }
+}
+
+/// a version 8 (i.e.: custom) UUID of the first 16 bytes of a digest,
+/// as RFC 9562 lays out
+fn uuid(digest: &[u8]) -> String {
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x80;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    [&hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..]].join("-")
+}
+
+/// a literal of the given datatype
+fn typed(datatype: &str, value: impl Into<String>) -> Value {
+    json!({ "@type": datatype, "@value": value.into() })
+}
@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use iocore::Path;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Rocksoft, Stat};
+
+/// progress of [`crate::TableV1::cs_resumable`] over a file, from which
+/// an interrupted run resumes rather than starting over.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// canonical path of the file
+    pub path: String,
+    /// stat of the file, which must not change until it is digested
+    pub stat: Stat,
+    /// bytes digested so far
+    pub offset: u64,
+    /// hex state of each hasher by algorithm
+    pub states: BTreeMap<String, String>,
+    /// state of each custom CRC by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub crcs: BTreeMap<String, CrcState>,
+}
+
+/// hex state of a custom CRC along with its parameters, so that a CRC
+/// of the same name but other parameters does not resume from it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CrcState {
+    pub crc: Rocksoft,
+    pub state: String,
+}
+
+impl Checkpoint {
+    pub fn read(path: &Path) -> Result<Checkpoint, Error> {
+        serde_yaml::from_str(&path.read()?)
+            .map_err(|e| Error::Error(format!("reading checkpoint {}: {}", path, e)))
+    }
+
+    /// writes a sibling file then renames it over `path`, so that an
+    /// interruption leaves the previous checkpoint intact
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        let partial = Path::raw(format!("{}.partial", path));
+        partial.write(serde_yaml::to_string(self)?.as_bytes())?;
+        partial.rename(path, false)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Error;
+
+/// rows of comma- or tab-separated values, one per document, under a
+/// header of the names of the columns, see [`crate::TableV1::columns`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimited {
+    /// RFC 4180, i.e.: fields holding a comma, a double quote or a line
+    /// break are quoted, doubling their double quotes, and rows end in
+    /// CRLF
+    Csv,
+    /// the tab-separated values of PostgreSQL and MySQL, i.e.: tabs,
+    /// line breaks and backslashes are escaped by a backslash, and rows
+    /// end in LF
+    Tsv,
+}
+
+impl Delimited {
+    /// a row of the given fields, line terminator included
+    pub fn row(&self, fields: &[impl AsRef<str>]) -> String {
+        let (separator, terminator) = match self {
+            Delimited::Csv => (",", "\r\n"),
+            Delimited::Tsv => ("\t", "\n"),
+        };
+        let fields = fields.iter().map(|field| self.field(field.as_ref())).collect::<Vec<_>>();
+        format!("{}{}", fields.join(separator), terminator)
+    }
+
+    pub fn field(&self, field: &str) -> String {
+        match self {
+            Delimited::Csv if field.contains([',', '"', '\r', '\n']) => {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            },
+            Delimited::Csv => field.to_string(),
+            Delimited::Tsv => field
+                .replace('\\', "\\\\")
+                .replace('\t', "\\t")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r"),
+        }
+    }
+
+    /// the row of a document under the given columns, whose names are
+    /// the keys of the document, or paths of nested keys joined by `.`
+    /// e.g.: `range.offset`. Missing values are left empty, strings are
+    /// written as they are and any other value as JSON.
+    pub fn document(&self, document: impl Serialize, columns: &[String]) -> Result<String, Error> {
+        let document = serde_json::to_value(document)
+            .map_err(|e| Error::Error(format!("encoding a row: {}", e)))?;
+        let fields = columns
+            .iter()
+            .map(|column| match document.pointer(&format!("/{}", column.replace('.', "/"))) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+            })
+            .collect::<Vec<_>>();
+        Ok(self.row(&fields))
+    }
+
+    /// the keys of a document in the order they are serialized, e.g.:
+    /// as the columns of documents which have no predefined ones
+    pub fn keys(document: impl Serialize) -> Result<Vec<String>, Error> {
+        match serde_json::to_value(document) {
+            Ok(Value::Object(object)) => Ok(object.keys().cloned().collect()),
+            Ok(_) => {
+                Err(Error::Error("only documents with keys can be written as rows".to_string()))
+            },
+            Err(e) => Err(Error::Error(format!("encoding a row: {}", e))),
+        }
+    }
+}
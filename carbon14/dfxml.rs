@@ -0,0 +1,172 @@
+use std::time::SystemTime;
+
+use iocore::PathDateTime;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{Algorithm, Error};
+
+/// namespace of the elements of DFXML
+pub const NAMESPACE: &str = "http://www.forensicswiki.org/wiki/Category:Digital_Forensics_XML";
+
+/// end of a DFXML document
+pub const FOOTER: &str = "</dfxml>\n";
+
+/// the program which wrote a DFXML document and where it ran, i.e.:
+/// its `<creator>` and `<execution_environment>`
+#[derive(Debug, Clone)]
+pub struct Creator {
+    pub version: String,
+    pub command_line: String,
+    pub host: String,
+    pub os_sysname: String,
+    pub arch: String,
+    pub start_time: PathDateTime,
+}
+
+impl Creator {
+    /// the carbon14 running in this process
+    pub fn current() -> Creator {
+        Creator {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            command_line: std::env::args().collect::<Vec<_>>().join(" "),
+            host: gethostname::gethostname().to_string_lossy().to_string(),
+            os_sysname: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            start_time: PathDateTime::from(SystemTime::now()),
+        }
+    }
+
+    /// the start of a DFXML document up to its first `<fileobject>`
+    pub fn header(&self) -> String {
+        [
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_string(),
+            format!(
+                "<dfxml xmlns=\"{}\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\" version=\"1.2.0\">",
+                NAMESPACE
+            ),
+            "  <metadata>".to_string(),
+            "    <dc:type>Hash List</dc:type>".to_string(),
+            "  </metadata>".to_string(),
+            "  <creator version=\"1.0\">".to_string(),
+            "    <program>carbon14</program>".to_string(),
+            element(4, "version", &self.version),
+            "    <execution_environment>".to_string(),
+            element(6, "os_sysname", &self.os_sysname),
+            element(6, "host", &self.host),
+            element(6, "arch", &self.arch),
+            element(6, "command_line", &self.command_line),
+            element(6, "start_time", &self.start_time.to_string()),
+            "    </execution_environment>".to_string(),
+            "  </creator>".to_string(),
+        ]
+        .map(|line| format!("{}\n", line))
+        .concat()
+    }
+}
+
+/// the `<fileobject>` of a [`crate::TableV1`] with its `filename`,
+/// `filesize`, `mtime`, `atime`, `crtime` and a `<hashdigest>` of each
+/// algorithm, except the fuzzy ones. Digests of a byte range and of
+/// pieces go in the `<byte_run>` of the bytes they digest, and tables
+/// of files which kept changing while digested have an `<error>`.
+pub fn fileobject(document: impl Serialize) -> Result<String, Error> {
+    let document = serde_json::to_value(document)
+        .map_err(|e| Error::Error(format!("encoding a fileobject: {}", e)))?;
+    let field = |name: &str| match document.get(name) {
+        None | Some(Value::Null) => None,
+        Some(Value::String(value)) => Some(value.clone()),
+        Some(value) => Some(value.to_string()),
+    };
+    let digests = Algorithm::every()
+        .filter(|algorithm| !Algorithm::FUZZY.contains(algorithm))
+        .filter_map(|algorithm| Some((algorithm.name(), field(algorithm.name())?)))
+        .collect::<Vec<_>>();
+    if field("filename").is_none() && digests.is_empty() {
+        return Err(Error::Error("only tables can be written as DFXML fileobjects".to_string()));
+    }
+    let mut lines = vec!["  <fileobject>".to_string()];
+    if let Some(filename) = field("filename") {
+        lines.push(element(4, "filename", &filename));
+    }
+    if document.get("unstable") == Some(&Value::Bool(true)) {
+        lines.push(element(4, "error", "changed while being digested"));
+    }
+    for (name, tag) in [("size", "filesize"), ("modified", "mtime"), ("accessed", "atime")] {
+        if let Some(value) = field(name) {
+            lines.push(element(4, tag, &value));
+        }
+    }
+    if let Some(created) = field("created") {
+        lines.push(element(4, "crtime", &created));
+    }
+    let hashdigests = |indent: usize, digests: &[(&str, String)]| {
+        digests
+            .iter()
+            .map(|(name, digest)| {
+                format!(
+                    "{:indent$}<hashdigest type=\"{}\">{}</hashdigest>",
+                    "",
+                    escape(name),
+                    escape(digest),
+                    indent = indent
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let range = document.get("range");
+    let pieces = document.pointer("/piecewise/pieces").and_then(Value::as_array);
+    if range.is_some() || pieces.is_some() {
+        lines.push("    <byte_runs>".to_string());
+        if let Some(range) = range {
+            let offset = range.get("offset").and_then(Value::as_u64).unwrap_or_default();
+            let size = document.get("size").and_then(Value::as_u64);
+            let length = range
+                .get("length")
+                .and_then(Value::as_u64)
+                .or(size.map(|size| size.saturating_sub(offset)));
+            lines.push(byte_run(offset, length));
+            lines.extend(hashdigests(8, &digests));
+            lines.push("      </byte_run>".to_string());
+        }
+        let algorithm = document.pointer("/piecewise/algorithm").and_then(Value::as_str);
+        for piece in pieces.into_iter().flatten() {
+            let offset = piece.get("offset").and_then(Value::as_u64).unwrap_or_default();
+            lines.push(byte_run(offset, piece.get("length").and_then(Value::as_u64)));
+            if let (Some(algorithm), Some(digest)) =
+                (algorithm, piece.get("digest").and_then(Value::as_str))
+            {
+                lines.extend(hashdigests(8, &[(algorithm, digest.to_string())]));
+            }
+            lines.push("      </byte_run>".to_string());
+        }
+        lines.push("    </byte_runs>".to_string());
+    }
+    if range.is_none() {
+        lines.extend(hashdigests(4, &digests));
+    }
+    lines.push("  </fileobject>".to_string());
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+/// the opening tag of a `<byte_run>` of the file, up to its end when
+/// `length` is [`None`]
+fn byte_run(offset: u64, length: Option<u64>) -> String {
+    match length {
+        Some(length) => format!("      <byte_run file_offset=\"{}\" len=\"{}\">", offset, length),
+        None => format!("      <byte_run file_offset=\"{}\">", offset),
+    }
+}
+
+fn element(indent: usize, tag: &str, text: &str) -> String {
+    format!("{:indent$}<{}>{}</{}>", "", tag, escape(text), tag, indent = indent)
+}
+
+/// escapes the characters of XML markup, be it in text or attributes
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
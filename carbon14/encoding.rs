@@ -0,0 +1,271 @@
+use std::str::FromStr;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde::{Deserialize, Serialize};
+
+use crate::{Algorithm, Error};
+
+/// RFC 4648 alphabet
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+/// alphabet of the base32 of Nix, which omits `e`, `o`, `u` and `t`
+const NIX32: &[u8; 32] = b"0123456789abcdfghijklmnpqrsvwxyz";
+
+/// textual forms of a [`RawDigest`]
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    /// lowercase hexadecimal, e.g.: as `sha256sum`
+    #[default]
+    Hex,
+    /// standard base64 with padding
+    Base64,
+    /// RFC 4648 base32 with padding
+    Base32,
+    /// base32 of Nix, e.g.: as `nix-hash --type sha256 --base32`
+    Nix32,
+    /// Subresource Integrity, e.g.: `sha512-<base64>` as in npm lockfiles,
+    /// which only defines `sha256`, `sha384` and `sha512`
+    Sri,
+    /// multihash in multibase base32, e.g.: `bciq<base32>` for sha256
+    Multihash,
+}
+
+impl Encoding {
+    pub const ALL: &'static [Encoding] = &[
+        Encoding::Hex,
+        Encoding::Base64,
+        Encoding::Base32,
+        Encoding::Nix32,
+        Encoding::Sri,
+        Encoding::Multihash,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encoding::Hex => "hex",
+            Encoding::Base64 => "base64",
+            Encoding::Base32 => "base32",
+            Encoding::Nix32 => "nix32",
+            Encoding::Sri => "sri",
+            Encoding::Multihash => "multihash",
+        }
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Encoding, Error> {
+        let name = name.trim().to_lowercase();
+        let names = Encoding::ALL.iter().map(Encoding::name).collect::<Vec<_>>().join(", ");
+        Encoding::ALL
+            .iter()
+            .find(|encoding| encoding.name() == name)
+            .copied()
+            .ok_or_else(|| {
+                Error::Error(format!("unknown encoding {:#?}, expected one of {}", name, names))
+            })
+    }
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// raw bytes of a digest of `bits` bits, optionally by a known
+/// algorithm, which [`RawDigest::encode`] renders in any [`Encoding`].
+/// [`Encoding::Sri`] and [`Encoding::Multihash`] name the algorithm, so
+/// digests of algorithms they don't define fall back to base64 and hex
+/// respectively.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RawDigest {
+    algorithm: Option<Algorithm>,
+    bytes: Vec<u8>,
+    bits: usize,
+}
+
+impl RawDigest {
+    pub fn new(algorithm: Option<Algorithm>, bytes: impl Into<Vec<u8>>) -> RawDigest {
+        let bytes = bytes.into();
+        RawDigest {
+            algorithm,
+            bits: bytes.len() * 8,
+            bytes,
+        }
+    }
+
+    /// digest of an algorithm which yields an integer, e.g.: a CRC,
+    /// whose `bits` lowest bits are kept in as few bytes as they take
+    pub fn from_value(algorithm: Option<Algorithm>, value: u128, bits: usize) -> RawDigest {
+        let bytes = value.to_be_bytes();
+        RawDigest {
+            algorithm,
+            bytes: bytes[bytes.len() - bits.div_ceil(8)..].to_vec(),
+            bits,
+        }
+    }
+
+    /// decodes a hex digest as written in a [`crate::TableV1`], where
+    /// CRCs narrower than a whole byte take an odd amount of digits,
+    /// which must be as many as [`Algorithm::bits`] take when known
+    pub fn from_hex(algorithm: Option<Algorithm>, digest: &str) -> Result<RawDigest, Error> {
+        let bits = algorithm.and_then(|algorithm| algorithm.bits()).unwrap_or(digest.len() * 4);
+        if digest.len() != bits.div_ceil(4) {
+            return Err(Error::Error(format!(
+                "{} digest {:#?} has {} hex digits rather than {}",
+                algorithm.map(|algorithm| algorithm.to_string()).unwrap_or_default(),
+                digest,
+                digest.len(),
+                bits.div_ceil(4)
+            )));
+        }
+        let bytes = if digest.len() % 2 == 1 {
+            hex::decode(format!("0{}", digest))?
+        } else {
+            hex::decode(digest)?
+        };
+        Ok(RawDigest {
+            algorithm,
+            bytes,
+            bits,
+        })
+    }
+
+    /// [`RawDigest::from_hex`] of a digest padded to another amount of
+    /// digits, as written by carbon14 before digests were typed, e.g.:
+    /// `crc16_xmodem` in 8 digits, or `crc64_ms` in fewer than 16
+    pub fn from_padded_hex(algorithm: Algorithm, digest: &str) -> Result<RawDigest, Error> {
+        let digits = match algorithm.bits() {
+            Some(bits) => bits.div_ceil(4),
+            None => return RawDigest::from_hex(Some(algorithm), digest),
+        };
+        let significant = digest.trim_start_matches('0');
+        if significant.len() > digits {
+            return RawDigest::from_hex(Some(algorithm), digest);
+        }
+        RawDigest::from_hex(Some(algorithm), &format!("{:0>digits$}", significant))
+    }
+
+    pub fn algorithm(&self) -> Option<Algorithm> {
+        self.algorithm
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    pub fn encode(&self, encoding: Encoding) -> String {
+        match encoding {
+            Encoding::Hex => self.hex(),
+            Encoding::Base64 => self.base64(),
+            Encoding::Base32 => self.base32(),
+            Encoding::Nix32 => self.nix32(),
+            Encoding::Sri => self.sri().unwrap_or_else(|| self.base64()),
+            Encoding::Multihash => self.multihash().unwrap_or_else(|| self.hex()),
+        }
+    }
+
+    /// lowercase hex in as many digits as `bits` take
+    pub fn hex(&self) -> String {
+        let hex = hex::encode(&self.bytes);
+        hex[hex.len() - self.bits.div_ceil(4)..].to_string()
+    }
+
+    pub fn base64(&self) -> String {
+        BASE64.encode(&self.bytes)
+    }
+
+    pub fn base32(&self) -> String {
+        let mut encoded = base32(&self.bytes, BASE32);
+        while encoded.len() % 8 != 0 {
+            encoded.push('=');
+        }
+        encoded
+    }
+
+    /// the base32 of Nix, which reads the bits from the last byte
+    /// backwards rather than in the order of RFC 4648
+    pub fn nix32(&self) -> String {
+        let length = (self.bytes.len() * 8).div_ceil(5);
+        (0..length)
+            .rev()
+            .map(|index| {
+                let bit = index * 5;
+                let (byte, shift) = (bit / 8, bit % 8);
+                let mut value = (self.bytes[byte] as u16) >> shift;
+                if let Some(next) = self.bytes.get(byte + 1) {
+                    value |= (*next as u16) << (8 - shift);
+                }
+                NIX32[(value & 0x1F) as usize] as char
+            })
+            .collect()
+    }
+
+    /// `<algorithm>-<base64>`, [`None`] for algorithms other than the
+    /// `sha256`, `sha384` and `sha512` of Subresource Integrity
+    pub fn sri(&self) -> Option<String> {
+        match self.algorithm? {
+            algorithm @ (Algorithm::Sha256 | Algorithm::Sha384 | Algorithm::Sha512) =>
+                Some(format!("{}-{}", algorithm, self.base64())),
+            _ => None,
+        }
+    }
+
+    /// the multihash of the digest in multibase base32, [`None`] for
+    /// algorithms without a multihash code, see [`Algorithm::multihash`]
+    pub fn multihash(&self) -> Option<String> {
+        let code = self.algorithm?.multihash()?;
+        let mut multihash = varint(code);
+        multihash.extend(varint(self.bytes.len() as u64));
+        multihash.extend_from_slice(&self.bytes);
+        Some(format!("b{}", base32(&multihash, BASE32).to_lowercase()))
+    }
+}
+
+impl std::fmt::Display for RawDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.hex())
+    }
+}
+
+/// base32 of the given alphabet without padding
+fn base32(bytes: &[u8], alphabet: &[u8; 32]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let (mut buffer, mut bits) = (0u16, 0u32);
+    for byte in bytes {
+        buffer = (buffer << 8) | *byte as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(alphabet[((buffer >> bits) & 0x1F) as usize] as char);
+        }
+        buffer &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        encoded.push(alphabet[((buffer << (5 - bits)) & 0x1F) as usize] as char);
+    }
+    encoded
+}
+
+/// unsigned LEB128 of multiformats
+fn varint(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::<u8>::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
@@ -0,0 +1,77 @@
+use iocore::Path;
+
+use crate::Error;
+
+/// context of the BLAKE3 key derivation of [`Key::fingerprint`]
+const FINGERPRINT_CONTEXT: &str = "carbon14 key fingerprint";
+
+/// secret key of the digests computed by [`crate::Digester::with_key`],
+/// only its `id` is ever written along with those digests.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key {
+    id: String,
+    secret: Vec<u8>,
+}
+
+impl Key {
+    /// `id` defaults to [`Key::fingerprint`]
+    pub fn new(id: Option<String>, secret: impl Into<Vec<u8>>) -> Result<Key, Error> {
+        let secret = secret.into();
+        if secret.is_empty() {
+            return Err(Error::Error("the key must not be empty".to_string()));
+        }
+        let id = match id {
+            Some(id) => id.trim().to_string(),
+            None => Key::fingerprint(&secret),
+        };
+        if id.is_empty() {
+            return Err(Error::Error("the key id must not be empty".to_string()));
+        }
+        Ok(Key { id, secret })
+    }
+
+    /// reads the key verbatim from a file, trailing newline included
+    pub fn from_file(path: &Path, id: Option<String>) -> Result<Key, Error> {
+        Key::new(id, path.read_bytes()?)
+            .map_err(|e| Error::Error(format!("reading key from {}: {}", path, e)))
+    }
+
+    /// reads the key verbatim from an environment variable
+    pub fn from_env(name: &str, id: Option<String>) -> Result<Key, Error> {
+        let secret = std::env::var_os(name)
+            .ok_or_else(|| Error::Error(format!("environment variable {} is not set", name)))?;
+        Key::new(id, secret.into_encoded_bytes())
+            .map_err(|e| Error::Error(format!("reading key from ${}: {}", name, e)))
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn secret(&self) -> &[u8] {
+        &self.secret
+    }
+
+    /// key of [`crate::Algorithm::Blake3`], i.e.: the secret itself
+    /// when it is 32 bytes long or its BLAKE3 digest otherwise, in the
+    /// manner of HMAC with keys longer than the block size
+    pub fn blake3(&self) -> [u8; 32] {
+        match <[u8; 32]>::try_from(self.secret.as_slice()) {
+            Ok(key) => key,
+            Err(_) => *blake3::hash(&self.secret).as_bytes(),
+        }
+    }
+
+    /// identifies a secret without revealing it, e.g.:
+    /// `b3:4a5f0c2e9d7b1863`
+    pub fn fingerprint(secret: &[u8]) -> String {
+        let digest = blake3::derive_key(FINGERPRINT_CONTEXT, secret);
+        format!("b3:{}", hex::encode(&digest[..8]))
+    }
+}
+
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Key").field("id", &self.id).finish_non_exhaustive()
+    }
+}
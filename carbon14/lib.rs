@@ -1,6 +1,29 @@
 pub mod errors;
 pub mod table;
 pub use errors::Error;
-pub use table::TableV1;
+pub mod case;
+pub mod checkpoint;
+pub use checkpoint::{Checkpoint, CrcState};
+pub mod delimited;
+pub use delimited::Delimited;
+pub mod dfxml;
+pub mod encoding;
+pub use encoding::{Encoding, RawDigest};
+pub mod key;
+pub use key::Key;
+pub use table::{Algorithm, Blake3Mode, ByteRange, Digester, Piece, Piecewise, TableV1};
 pub mod sys;
-pub use sys::{clipboard_lines, stdin_lines};
+pub use sys::{clipboard_lines, relative_to_cwd, stdin_lines};
+pub mod manifest;
+pub mod merkle;
+pub use merkle::{Merkle, MerkleSummary};
+pub(crate) mod resumable;
+pub mod rocksoft;
+pub use rocksoft::Rocksoft;
+pub mod selftest;
+pub mod similarity;
+pub use similarity::Similarity;
+pub mod stat;
+pub use stat::Stat;
+pub mod sums;
+pub use sums::Sums;
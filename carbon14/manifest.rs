@@ -0,0 +1,35 @@
+use iocore::Path;
+
+use crate::{Error, TableV1};
+
+/// marker written by carbon14 at the end of every document
+pub const END_OF_DOCUMENT: &str = "#\t∎";
+
+/// parses the tables of a `.c14` manifest, i.e.: YAML documents
+/// separated by `---` and terminated by [`END_OF_DOCUMENT`], skipping
+/// the [`crate::MerkleSummary`] documents. Digests padded to another
+/// width by older versions are only accepted when `padded`, see
+/// [`TableV1::canonical`].
+pub fn parse(manifest: &str, padded: bool) -> Result<Vec<TableV1>, Error> {
+    let mut tables = Vec::<TableV1>::new();
+    for document in manifest.split("\n---\n") {
+        let document = document.trim();
+        // the marker directly follows the last value of the document,
+        // where YAML would otherwise read it as part of that value
+        let document = document.strip_suffix(END_OF_DOCUMENT).unwrap_or(document).trim();
+        if document.is_empty() || document == "---" {
+            continue;
+        }
+        let document = serde_yaml::from_str::<serde_yaml::Value>(document)?;
+        if document.get("merkle").is_some() {
+            continue;
+        }
+        tables.push(serde_yaml::from_value::<TableV1>(document)?.canonical(padded)?);
+    }
+    Ok(tables)
+}
+
+/// reads the tables of the `.c14` manifest at the given path
+pub fn read(path: &Path, padded: bool) -> Result<Vec<TableV1>, Error> {
+    parse(&path.read()?, padded).map_err(|e| Error::Error(format!("reading manifest {}: {}", path, e)))
+}
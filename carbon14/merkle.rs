@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Algorithm, Digester};
+
+/// tree of the digests of the files within a directory whose every
+/// directory is digested from its children sorted by name, i.e.: the
+/// concatenation of `<type>\t<digest>\t<name>\0` where type is either
+/// `file` or `directory`, so that equal trees have equal digests
+/// regardless of where they are or the order in which they are read.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Merkle {
+    children: BTreeMap<String, Node>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    File(String),
+    Directory(Merkle),
+}
+
+/// document summarizing the [`Merkle`] digests of a scanned directory
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MerkleSummary {
+    pub root: String,
+    pub merkle: String,
+    pub digest: String,
+    /// digests of the subdirectories by their path relative to `root`
+    pub directories: BTreeMap<String, String>,
+}
+
+impl Merkle {
+    pub fn new() -> Merkle {
+        Merkle::default()
+    }
+
+    /// adds the digest of a file at a `/`-separated path relative to
+    /// the root of the tree
+    pub fn insert_file(&mut self, path: &str, digest: &str) {
+        let (parent, name) = match path.rsplit_once('/') {
+            Some((parent, name)) => (self.directory(parent), name),
+            None => (self, path),
+        };
+        parent.children.insert(name.to_string(), Node::File(digest.to_string()));
+    }
+
+    /// adds a directory, which is only needed for empty directories
+    pub fn insert_directory(&mut self, path: &str) {
+        self.directory(path);
+    }
+
+    fn directory(&mut self, path: &str) -> &mut Merkle {
+        let mut directory = self;
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            let node = directory
+                .children
+                .entry(name.to_string())
+                .or_insert_with(|| Node::Directory(Merkle::new()));
+            if let Node::File(_) = node {
+                *node = Node::Directory(Merkle::new());
+            }
+            directory = match node {
+                Node::Directory(merkle) => merkle,
+                Node::File(_) => unreachable!(),
+            };
+        }
+        directory
+    }
+
+    /// digest of the root of the tree by the given algorithm, which
+    /// must have digested the files as well
+    pub fn digest(&self, algorithm: Algorithm, digester: &Digester) -> String {
+        self.digests(algorithm, digester, "", &mut BTreeMap::new())
+    }
+
+    pub fn summary(&self, root: &str, algorithm: Algorithm, digester: &Digester) -> MerkleSummary {
+        let mut directories = BTreeMap::<String, String>::new();
+        let digest = self.digests(algorithm, digester, "", &mut directories);
+        MerkleSummary {
+            root: root.to_string(),
+            merkle: algorithm.to_string(),
+            digest,
+            directories,
+        }
+    }
+
+    fn digests(
+        &self,
+        algorithm: Algorithm,
+        digester: &Digester,
+        path: &str,
+        directories: &mut BTreeMap<String, String>,
+    ) -> String {
+        let mut listing = Vec::<u8>::new();
+        for (name, node) in &self.children {
+            let (kind, digest) = match node {
+                Node::File(digest) => ("file", digest.clone()),
+                Node::Directory(merkle) => {
+                    let path = format!("{}{}", path, name);
+                    let digest =
+                        merkle.digests(algorithm, digester, &format!("{}/", path), directories);
+                    directories.insert(path, digest.clone());
+                    ("directory", digest)
+                },
+            };
+            listing.extend_from_slice(format!("{}\t{}\t{}\0", kind, digest, name).as_bytes());
+        }
+        digester.digest(algorithm, &listing)
+    }
+}
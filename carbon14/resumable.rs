@@ -0,0 +1,370 @@
+use sha2::digest::generic_array::GenericArray;
+
+use crate::Error;
+
+/// bytes of the state of [`keccak::f1600`]
+const LANES: usize = 25 * 8;
+
+/// per-round constants of MD5, i.e.: `floor(abs(sin(i + 1)) * 2^32)`
+#[rustfmt::skip]
+const MD5_SINES: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee,
+    0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be,
+    0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa,
+    0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed,
+    0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c,
+    0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05,
+    0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039,
+    0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1,
+    0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// per-round left rotations of MD5
+#[rustfmt::skip]
+const MD5_SHIFTS: [u32; 16] = [7, 12, 17, 22, 5, 9, 14, 20, 4, 11, 16, 23, 6, 10, 15, 21];
+
+pub(crate) const MD5: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+pub(crate) const SHA1: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+pub(crate) const SHA224: [u32; 8] = [
+    0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
+];
+pub(crate) const SHA256: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+pub(crate) const SHA384: [u64; 8] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+pub(crate) const SHA512: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+pub(crate) const SHA512_224: [u64; 8] = [
+    0x8c3d37c819544da2,
+    0x73e1996689dcd4d6,
+    0x1dfab7ae32ff9c82,
+    0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8,
+    0x77e36f7304c48942,
+    0x3f9d85a86a1d36c8,
+    0x1112e6ad91d692a1,
+];
+pub(crate) const SHA512_256: [u64; 8] = [
+    0x22312194fc2bf72c,
+    0x9f555fa3c84c64c2,
+    0x2393b86b6f53b151,
+    0x963877195940eabd,
+    0x96283ee2a88effe3,
+    0xbe5e1e2553863992,
+    0x2b0199fc2c85b8aa,
+    0x0eb72ddc81c52ca2,
+];
+
+/// compression function of a Merkle–Damgård hash over its chaining
+/// value, of which the state of a [`BlockDigest`] consists
+pub(crate) trait Compress: Copy + Send {
+    /// bytes per block
+    const BLOCK: usize;
+    /// bytes of the chaining value
+    const STATE: usize;
+    /// compresses as many whole blocks as given
+    fn compress(&mut self, blocks: &[u8]);
+    /// the message length in bits as appended by the padding
+    fn length(bits: u128) -> Vec<u8>;
+    /// the chaining value in the byte order of the digest
+    fn to_bytes(&self) -> Vec<u8>;
+    fn from_bytes(bytes: &[u8]) -> Self;
+}
+
+impl Compress for [u32; 4] {
+    const BLOCK: usize = 64;
+    const STATE: usize = 16;
+
+    fn compress(&mut self, blocks: &[u8]) {
+        for block in blocks.chunks_exact(Self::BLOCK) {
+            let mut words = [0u32; 16];
+            for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+                *word = u32::from_le_bytes(bytes.try_into().unwrap());
+            }
+            let [mut a, mut b, mut c, mut d] = *self;
+            macro_rules! rounds {
+                ($start:literal, $index:expr, $f:expr) => {
+                    for round in $start..$start + 16 {
+                        let f = $f(b, c, d)
+                            .wrapping_add(a)
+                            .wrapping_add(MD5_SINES[round])
+                            .wrapping_add(words[$index(round) % 16]);
+                        (a, d, c) = (d, c, b);
+                        b = b.wrapping_add(f.rotate_left(MD5_SHIFTS[$start / 4 + round % 4]));
+                    }
+                };
+            }
+            rounds!(0, |round| round, |b: u32, c: u32, d: u32| (b & c) | (!b & d));
+            rounds!(16, |round| 5 * round + 1, |b: u32, c: u32, d: u32| (d & b) | (!d & c));
+            rounds!(32, |round| 3 * round + 5, |b: u32, c: u32, d: u32| b ^ c ^ d);
+            rounds!(48, |round| 7 * round, |b: u32, c: u32, d: u32| c ^ (b | !d));
+            for (word, value) in self.iter_mut().zip([a, b, c, d]) {
+                *word = word.wrapping_add(value);
+            }
+        }
+    }
+
+    fn length(bits: u128) -> Vec<u8> {
+        (bits as u64).to_le_bytes().to_vec()
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut state = [0u32; 4];
+        for (word, bytes) in state.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+        state
+    }
+}
+
+macro_rules! compress {
+    ($word:ty, $words:literal, $length:ty, $block:literal, $compress:path) => {
+        impl Compress for [$word; $words] {
+            const BLOCK: usize = $block;
+            const STATE: usize = std::mem::size_of::<$word>() * $words;
+
+            fn compress(&mut self, blocks: &[u8]) {
+                for block in blocks.chunks_exact(Self::BLOCK) {
+                    $compress(self, std::slice::from_ref(GenericArray::from_slice(block)));
+                }
+            }
+
+            fn length(bits: u128) -> Vec<u8> {
+                (bits as $length).to_be_bytes().to_vec()
+            }
+
+            fn to_bytes(&self) -> Vec<u8> {
+                self.iter().flat_map(|word| word.to_be_bytes()).collect()
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Self {
+                let mut state = [0; $words];
+                let size = std::mem::size_of::<$word>();
+                for (word, bytes) in state.iter_mut().zip(bytes.chunks_exact(size)) {
+                    *word = <$word>::from_be_bytes(bytes.try_into().unwrap());
+                }
+                state
+            }
+        }
+    };
+}
+compress!(u32, 5, u64, 64, sha1::compress);
+compress!(u32, 8, u64, 64, sha2::compress256);
+compress!(u64, 8, u128, 128, sha2::compress512);
+
+/// a Merkle–Damgård digest, i.e.: MD5, SHA-1 or SHA-2, of which the
+/// state can be exported and restored unlike that of the [`sha2::Digest`]
+/// implementations
+#[derive(Debug, Clone)]
+pub(crate) struct BlockDigest<C> {
+    state: C,
+    buffer: Vec<u8>,
+    length: u64,
+    size: usize,
+}
+
+impl<C: Compress> BlockDigest<C> {
+    /// digest of `size` bytes of the chaining value starting from `iv`
+    pub(crate) fn new(iv: C, size: usize) -> BlockDigest<C> {
+        BlockDigest {
+            state: iv,
+            buffer: Vec::with_capacity(C::BLOCK),
+            length: 0,
+            size,
+        }
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        self.length += data.len() as u64;
+        if !self.buffer.is_empty() {
+            let length = (C::BLOCK - self.buffer.len()).min(data.len());
+            self.buffer.extend_from_slice(&data[..length]);
+            data = &data[length..];
+            if self.buffer.len() < C::BLOCK {
+                return;
+            }
+            self.state.compress(&self.buffer);
+            self.buffer.clear();
+        }
+        let whole = data.len() - data.len() % C::BLOCK;
+        self.state.compress(&data[..whole]);
+        self.buffer.extend_from_slice(&data[whole..]);
+    }
+
+    /// bytes of the digest
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
+    pub(crate) fn finalize(mut self) -> Vec<u8> {
+        let length = C::length(self.length as u128 * 8);
+        self.buffer.push(0x80);
+        while self.buffer.len() % C::BLOCK != C::BLOCK - length.len() {
+            self.buffer.push(0);
+        }
+        self.buffer.extend(length);
+        self.state.compress(&self.buffer);
+        let mut digest = self.state.to_bytes();
+        digest.truncate(self.size);
+        digest
+    }
+
+    /// the chaining value, the length so far in big-endian and the
+    /// bytes of the incomplete block
+    pub(crate) fn state(&self) -> Vec<u8> {
+        let mut state = self.state.to_bytes();
+        state.extend(self.length.to_be_bytes());
+        state.extend_from_slice(&self.buffer);
+        state
+    }
+
+    pub(crate) fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+        let (chain, rest) = state
+            .split_at_checked(C::STATE)
+            .ok_or_else(|| Error::Error(format!("truncated state of {} bytes", state.len())))?;
+        let (length, buffer) = rest
+            .split_first_chunk::<8>()
+            .ok_or_else(|| Error::Error(format!("truncated state of {} bytes", state.len())))?;
+        let length = u64::from_be_bytes(*length);
+        if buffer.len() as u64 != length % C::BLOCK as u64 {
+            return Err(Error::Error(format!(
+                "state of {} bytes buffers {} bytes rather than {}",
+                length,
+                buffer.len(),
+                length % C::BLOCK as u64
+            )));
+        }
+        self.state = C::from_bytes(chain);
+        self.length = length;
+        self.buffer = buffer.to_vec();
+        Ok(())
+    }
+}
+
+/// a Keccak sponge, i.e.: SHA-3, Keccak or SHAKE, of which the state can
+/// be exported and restored unlike that of the [`sha3`] implementations
+#[derive(Debug, Clone)]
+pub(crate) struct Sponge {
+    lanes: [u64; 25],
+    rate: usize,
+    padding: u8,
+    buffer: Vec<u8>,
+    size: usize,
+}
+
+impl Sponge {
+    /// `rate` in bytes, `padding` being the domain separation bits and
+    /// the first bit of the padding, e.g.: `0x06` for SHA-3, `0x01` for
+    /// Keccak and `0x1f` for SHAKE
+    pub(crate) fn new(rate: usize, padding: u8, size: usize) -> Sponge {
+        Sponge {
+            lanes: [0; 25],
+            rate,
+            padding,
+            buffer: Vec::with_capacity(rate),
+            size,
+        }
+    }
+
+    /// bytes of the digest
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
+    fn absorb(&mut self, block: &[u8]) {
+        for (lane, bytes) in self.lanes.iter_mut().zip(block.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        keccak::f1600(&mut self.lanes);
+    }
+
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        if !self.buffer.is_empty() {
+            let length = (self.rate - self.buffer.len()).min(data.len());
+            self.buffer.extend_from_slice(&data[..length]);
+            data = &data[length..];
+            if self.buffer.len() < self.rate {
+                return;
+            }
+            let block = std::mem::take(&mut self.buffer);
+            self.absorb(&block);
+        }
+        let mut blocks = data.chunks_exact(self.rate);
+        for block in &mut blocks {
+            self.absorb(block);
+        }
+        self.buffer.extend_from_slice(blocks.remainder());
+    }
+
+    pub(crate) fn finalize(mut self) -> Vec<u8> {
+        let mut block = std::mem::take(&mut self.buffer);
+        let length = block.len();
+        block.resize(self.rate, 0);
+        block[length] ^= self.padding;
+        block[self.rate - 1] ^= 0x80;
+        self.absorb(&block);
+        let mut digest = Vec::<u8>::with_capacity(self.size);
+        loop {
+            for lane in &self.lanes[..self.rate / 8] {
+                digest.extend(lane.to_le_bytes());
+            }
+            if digest.len() >= self.size {
+                digest.truncate(self.size);
+                return digest;
+            }
+            keccak::f1600(&mut self.lanes);
+        }
+    }
+
+    /// the lanes in little-endian followed by the bytes of the
+    /// incomplete block
+    pub(crate) fn state(&self) -> Vec<u8> {
+        let mut state = self.lanes.iter().flat_map(|lane| lane.to_le_bytes()).collect::<Vec<_>>();
+        state.extend_from_slice(&self.buffer);
+        state
+    }
+
+    pub(crate) fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+        if state.len() < LANES || state.len() - LANES >= self.rate {
+            return Err(Error::Error(format!(
+                "state of {} bytes is not {} bytes of lanes and less than {} buffered",
+                state.len(),
+                LANES,
+                self.rate
+            )));
+        }
+        for (lane, bytes) in self.lanes.iter_mut().zip(state.chunks_exact(8)) {
+            *lane = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        self.buffer = state[LANES..].to_vec();
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use std::str::FromStr;
+
+use iocore::Path;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, RawDigest};
+
+/// CRC defined by the parameters of the Rocksoft model, e.g.:
+/// `CRC-16/KERMIT` is `width: 16, poly: 0x1021, init: 0, refin: true,
+/// refout: true, xorout: 0, check: 0x2189`, whose digest is named
+/// after `name` in a [`crate::TableV1`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rocksoft {
+    pub name: String,
+    /// amount of bits, from 1 to 64
+    pub width: u8,
+    pub poly: u64,
+    #[serde(default)]
+    pub init: u64,
+    #[serde(default)]
+    pub refin: bool,
+    /// defaults to `refin`
+    #[serde(default)]
+    pub refout: Option<bool>,
+    #[serde(default)]
+    pub xorout: u64,
+    /// CRC of the ASCII string `123456789`, validated when present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check: Option<u64>,
+}
+
+impl Rocksoft {
+    /// reads a YAML list of CRCs, e.g.:
+    ///
+    /// ```yaml
+    /// - name: firmware
+    ///   width: 16
+    ///   poly: 0x8005
+    ///   init: 0xffff
+    ///   refin: true
+    ///   check: 0x4b37
+    /// ```
+    pub fn load(path: &Path) -> Result<Vec<Rocksoft>, Error> {
+        let crcs = serde_yaml::from_str::<Vec<Rocksoft>>(&path.read()?)
+            .map_err(|e| Error::Error(format!("reading CRCs from {}: {}", path, e)))?;
+        for crc in &crcs {
+            crc.validate()?;
+        }
+        Ok(crcs)
+    }
+
+    pub fn refout(&self) -> bool {
+        self.refout.unwrap_or(self.refin)
+    }
+
+    fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.width as u32)
+    }
+
+    /// ensures the parameters fit in `width` and, when given, that the
+    /// CRC of `123456789` matches `check`
+    pub fn validate(&self) -> Result<(), Error> {
+        let valid = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_';
+        if self.name.is_empty() || !self.name.chars().all(valid) {
+            return Err(Error::Error(format!(
+                "CRC name {:#?} must only contain lowercase letters, digits and underscores",
+                self.name
+            )));
+        }
+        if self.width == 0 || self.width > 64 {
+            return Err(Error::Error(format!(
+                "CRC {}: width {} is not between 1 and 64",
+                self.name, self.width
+            )));
+        }
+        for (parameter, value) in
+            [("poly", self.poly), ("init", self.init), ("xorout", self.xorout)]
+        {
+            if value & !self.mask() != 0 {
+                return Err(Error::Error(format!(
+                    "CRC {}: {} 0x{:x} does not fit in {} bits",
+                    self.name, parameter, value, self.width
+                )));
+            }
+        }
+        if let Some(check) = self.check {
+            let mut digest = self.digest();
+            digest.update(b"123456789");
+            let crc = digest.value();
+            if crc != check {
+                return Err(Error::Error(format!(
+                    "CRC {}: check 0x{:x} does not match 0x{:x}, the CRC of \"123456789\"",
+                    self.name, check, crc
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn digest(&self) -> RocksoftDigest {
+        let shift = 64 - self.width as u32;
+        let mut table = [0u64; 256];
+        if self.refin {
+            let poly = self.poly.reverse_bits() >> shift;
+            for (byte, entry) in table.iter_mut().enumerate() {
+                let mut crc = byte as u64;
+                for _ in 0..8 {
+                    crc = if crc & 1 == 1 { (crc >> 1) ^ poly } else { crc >> 1 };
+                }
+                *entry = crc;
+            }
+        } else {
+            let poly = self.poly << shift;
+            for (byte, entry) in table.iter_mut().enumerate() {
+                let mut crc = (byte as u64) << 56;
+                for _ in 0..8 {
+                    crc = if crc >> 63 == 1 { (crc << 1) ^ poly } else { crc << 1 };
+                }
+                *entry = crc;
+            }
+        }
+        let register =
+            if self.refin { self.init.reverse_bits() >> shift } else { self.init << shift };
+        RocksoftDigest {
+            crc: self.clone(),
+            table,
+            register,
+        }
+    }
+}
+
+/// parses `NAME:width=W,poly=P[,init=I][,refin=B][,refout=B][,xorout=X][,check=C]`
+/// where numbers are either decimal or hexadecimal prefixed by `0x`
+impl FromStr for Rocksoft {
+    type Err = Error;
+
+    fn from_str(spec: &str) -> Result<Rocksoft, Error> {
+        let invalid = |reason: String| Error::Error(format!("CRC {:#?}: {}", spec, reason));
+        let (name, parameters) = spec
+            .split_once(':')
+            .ok_or_else(|| invalid("expected NAME:width=W,poly=P,...".to_string()))?;
+        let mut crc = Rocksoft {
+            name: name.trim().to_string(),
+            width: 0,
+            poly: 0,
+            init: 0,
+            refin: false,
+            refout: None,
+            xorout: 0,
+            check: None,
+        };
+        let mut poly = false;
+        for parameter in parameters.split(',').filter(|parameter| !parameter.trim().is_empty()) {
+            let (key, value) = parameter
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected KEY=VALUE, got {:#?}", parameter)))?;
+            let value = value.trim();
+            let number = || {
+                match value.strip_prefix("0x") {
+                    Some(hex) => u64::from_str_radix(hex, 16),
+                    None => value.parse::<u64>(),
+                }
+                .map_err(|e| invalid(format!("{}={}: {}", key, value, e)))
+            };
+            let boolean =
+                || value.parse::<bool>().map_err(|e| invalid(format!("{}={}: {}", key, value, e)));
+            match key.trim() {
+                "width" => {
+                    crc.width = u8::try_from(number()?)
+                        .map_err(|e| invalid(format!("width={}: {}", value, e)))?
+                },
+                "poly" => {
+                    crc.poly = number()?;
+                    poly = true;
+                },
+                "init" => crc.init = number()?,
+                "refin" => crc.refin = boolean()?,
+                "refout" => crc.refout = Some(boolean()?),
+                "xorout" => crc.xorout = number()?,
+                "check" => crc.check = Some(number()?),
+                key => return Err(invalid(format!("unknown parameter {:#?}", key))),
+            }
+        }
+        if !poly {
+            return Err(invalid("missing poly".to_string()));
+        }
+        crc.validate()?;
+        Ok(crc)
+    }
+}
+
+/// a [`Rocksoft`] CRC in progress, the register is kept reflected when
+/// `refin` is set and left-aligned to 64 bits otherwise so that widths
+/// under 8 bits need no special handling
+#[derive(Debug, Clone)]
+pub struct RocksoftDigest {
+    crc: Rocksoft,
+    table: [u64; 256],
+    register: u64,
+}
+
+impl RocksoftDigest {
+    pub fn update(&mut self, data: &[u8]) {
+        if self.crc.refin {
+            for byte in data {
+                let index = (self.register as u8 ^ byte) as usize;
+                self.register = self.table[index] ^ (self.register >> 8);
+            }
+        } else {
+            for byte in data {
+                let index = ((self.register >> 56) as u8 ^ byte) as usize;
+                self.register = self.table[index] ^ (self.register << 8);
+            }
+        }
+    }
+
+    /// the register as kept between updates, e.g.: to resume from a
+    /// [`crate::Checkpoint`]
+    pub fn register(&self) -> u64 {
+        self.register
+    }
+
+    pub fn set_register(&mut self, register: u64) {
+        self.register = register;
+    }
+
+    pub fn width(&self) -> u8 {
+        self.crc.width
+    }
+
+    /// CRC of the data so far
+    pub fn value(&self) -> u64 {
+        let shift = 64 - self.crc.width as u32;
+        let crc = if self.crc.refin { self.register } else { self.register >> shift };
+        let crc = if self.crc.refin == self.crc.refout() {
+            crc
+        } else {
+            crc.reverse_bits() >> shift
+        };
+        (crc ^ self.crc.xorout) & self.crc.mask()
+    }
+
+    /// the value rendered in as many hex digits as `width` takes
+    pub fn hex(&self) -> String {
+        RawDigest::from_value(None, self.value() as u128, self.crc.width as usize).hex()
+    }
+}
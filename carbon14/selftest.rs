@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Algorithm, Digester, RawDigest, TableV1};
+
+/// published known-answer test vector of an algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vector {
+    pub algorithm: Algorithm,
+    pub input: &'static [u8],
+    /// times the input is repeated, e.g.: a million times `a`
+    pub repeat: usize,
+    /// customization string of cSHAKE, empty otherwise
+    pub customization: &'static str,
+    pub expected: &'static str,
+    pub source: &'static str,
+}
+
+const fn vector(
+    algorithm: Algorithm,
+    input: &'static [u8],
+    expected: &'static str,
+    source: &'static str,
+) -> Vector {
+    Vector {
+        algorithm,
+        input,
+        repeat: 1,
+        customization: "",
+        expected,
+        source,
+    }
+}
+
+/// vector of the input repeated `repeat` times, which spans several
+/// chunks of [`crate::CHUNK_SIZE`] when long enough
+const fn repeated(
+    algorithm: Algorithm,
+    input: &'static [u8],
+    repeat: usize,
+    expected: &'static str,
+    source: &'static str,
+) -> Vector {
+    Vector {
+        repeat,
+        ..vector(algorithm, input, expected, source)
+    }
+}
+
+const FIPS_180_2_BLOCKS: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+const FIPS_180_4_BLOCKS: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+const GOST_M1: &[u8] = b"012345678901234567890123456789012345678901234567890123456789012";
+const MILLION: usize = 1_000_000;
+
+/// known-answer vectors of the algorithms of [`crate::TableV1`] other
+/// than the CRCs, which are checked against their catalogue instead,
+/// see [`Algorithm::check`]
+#[rustfmt::skip]
+pub const VECTORS: &[Vector] = &[
+    vector(Algorithm::Md5, b"", "d41d8cd98f00b204e9800998ecf8427e", "RFC 1321"),
+    vector(Algorithm::Md5, b"a", "0cc175b9c0f1b6a831c399e269772661", "RFC 1321"),
+    vector(Algorithm::Md5, b"abc", "900150983cd24fb0d6963f7d28e17f72", "RFC 1321"),
+    vector(Algorithm::Md5, b"message digest", "f96b697d7cb7938d525a2f31aaf161d0", "RFC 1321"),
+    vector(Algorithm::Md5, b"abcdefghijklmnopqrstuvwxyz", "c3fcd3d76192e4007dfb496cca67e13b", "RFC 1321"),
+    vector(Algorithm::Md5, b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", "d174ab98d277d9f5a5611c2c9f419d9f", "RFC 1321"),
+    vector(Algorithm::Md5, b"12345678901234567890123456789012345678901234567890123456789012345678901234567890", "57edf4a22be3c955ac49da2e2107b67a", "RFC 1321"),
+    repeated(Algorithm::Md5, b"a", MILLION, "7707d6ae4e027c70eea2a935c2296f21", "RFC 1321"),
+    vector(Algorithm::Sha1, b"abc", "a9993e364706816aba3e25717850c26c9cd0d89d", "FIPS 180-4"),
+    vector(Algorithm::Sha1, FIPS_180_2_BLOCKS, "84983e441c3bd26ebaae4aa1f95129e5e54670f1", "FIPS 180-4"),
+    repeated(Algorithm::Sha1, b"a", MILLION, "34aa973cd4c4daa4f61eeb2bdbad27316534016f", "FIPS 180-4"),
+    vector(Algorithm::Sha224, b"abc", "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7", "FIPS 180-4"),
+    vector(Algorithm::Sha224, FIPS_180_2_BLOCKS, "75388b16512776cc5dba5da1fd890150b0c6455cb4f58b1952522525", "FIPS 180-4"),
+    repeated(Algorithm::Sha224, b"a", MILLION, "20794655980c91d8bbb4c1ea97618a4bf03f42581948b2ee4ee7ad67", "FIPS 180-4"),
+    vector(Algorithm::Sha256, b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad", "FIPS 180-4"),
+    vector(Algorithm::Sha256, FIPS_180_2_BLOCKS, "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1", "FIPS 180-4"),
+    repeated(Algorithm::Sha256, b"a", MILLION, "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0", "FIPS 180-4"),
+    vector(Algorithm::Sha384, b"abc", "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7", "FIPS 180-4"),
+    vector(Algorithm::Sha384, FIPS_180_4_BLOCKS, "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039", "FIPS 180-4"),
+    repeated(Algorithm::Sha384, b"a", MILLION, "9d0e1809716474cb086e834e310a4a1ced149e9c00f248527972cec5704c2a5b07b8b3dc38ecc4ebae97ddd87f3d8985", "FIPS 180-4"),
+    vector(Algorithm::Sha512, b"abc", "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f", "FIPS 180-4"),
+    vector(Algorithm::Sha512, FIPS_180_4_BLOCKS, "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909", "FIPS 180-4"),
+    repeated(Algorithm::Sha512, b"a", MILLION, "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973ebde0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b", "FIPS 180-4"),
+    vector(Algorithm::Sha512_224, b"abc", "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa", "FIPS 180-4"),
+    vector(Algorithm::Sha512_224, FIPS_180_4_BLOCKS, "23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9", "FIPS 180-4"),
+    repeated(Algorithm::Sha512_224, b"a", MILLION, "37ab331d76f0d36de422bd0edeb22a28accd487b7a8453ae965dd287", "FIPS 180-4"),
+    vector(Algorithm::Sha512_256, b"abc", "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23", "FIPS 180-4"),
+    vector(Algorithm::Sha512_256, FIPS_180_4_BLOCKS, "3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a", "FIPS 180-4"),
+    repeated(Algorithm::Sha512_256, b"a", MILLION, "9a59a052930187a97038cae692f30708aa6491923ef5194394dc68d56c74fb21", "FIPS 180-4"),
+    vector(Algorithm::Sha3_224, b"", "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7", "FIPS 202"),
+    vector(Algorithm::Sha3_224, b"abc", "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf", "FIPS 202"),
+    repeated(Algorithm::Sha3_224, b"a", MILLION, "d69335b93325192e516a912e6d19a15cb51c6ed5c15243e7a7fd653c", "FIPS 202"),
+    vector(Algorithm::Sha3_256, b"", "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a", "FIPS 202"),
+    vector(Algorithm::Sha3_256, b"abc", "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532", "FIPS 202"),
+    repeated(Algorithm::Sha3_256, b"a", MILLION, "5c8875ae474a3634ba4fd55ec85bffd661f32aca75c6d699d0cdcb6c115891c1", "FIPS 202"),
+    vector(Algorithm::Sha3_384, b"", "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004", "FIPS 202"),
+    vector(Algorithm::Sha3_384, b"abc", "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25", "FIPS 202"),
+    repeated(Algorithm::Sha3_384, b"a", MILLION, "eee9e24d78c1855337983451df97c8ad9eedf256c6334f8e948d252d5e0e76847aa0774ddb90a842190d2c558b4b8340", "FIPS 202"),
+    vector(Algorithm::Sha3_512, b"", "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26", "FIPS 202"),
+    vector(Algorithm::Sha3_512, b"abc", "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0", "FIPS 202"),
+    repeated(Algorithm::Sha3_512, b"a", MILLION, "3c3a876da14034ab60627c077bb98f7e120a2a5370212dffb3385a18d4f38859ed311d0a9d5141ce9cc5c66ee689b266a8aa18ace8282a0e0db596c90b0a7b87", "FIPS 202"),
+    vector(Algorithm::Shake128, b"", "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26", "FIPS 202"),
+    vector(Algorithm::Shake256, b"", "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be", "FIPS 202"),
+    Vector {
+        algorithm: Algorithm::Cshake128,
+        input: &[0x00, 0x01, 0x02, 0x03],
+        repeat: 1,
+        customization: "Email Signature",
+        expected: "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5",
+        source: "NIST SP 800-185 cSHAKE sample #1",
+    },
+    Vector {
+        algorithm: Algorithm::Cshake256,
+        input: &[0x00, 0x01, 0x02, 0x03],
+        repeat: 1,
+        customization: "Email Signature",
+        expected: "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd164020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c",
+        source: "NIST SP 800-185 cSHAKE sample #3",
+    },
+    vector(Algorithm::Keccak224, b"", "f71837502ba8e10837bdd8d365adb85591895602fc552b48b7390abd", "Keccak reference"),
+    vector(Algorithm::Keccak256, b"", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470", "Keccak reference"),
+    vector(Algorithm::Keccak256Full, b"de omnibus dubitandum", "628ec2906870ea008e81ad1901ba731e06d4a94d5eacfef0276dbc9d91cd28602fedfb134e5a4c956bc7782b36cb71f46624ddad5b1ab6eae1e129a07bb4bdf901dbd2d1c2a23f9bfd40265df32464142eda9689364a943779b57b6b20017b14895643218b52a2ed4e18f80e0e6415900c91246951eca6049504bf275e5ce0d23dea3749ba397f6e394b7e0475c701d184b1339e7e14a5e923053cea50c49981ede41ea861bf53fb4fbd72a922ce8b57becad7cc8dc1d17f8c4555b275e27e50b840e6b8a7b4e74f", "RustCrypto sha3 test vectors"),
+    vector(Algorithm::Keccak256Full, b"caveat emptor", "21bc6cee441f1e124e25a486597006ac58713a4cebab192fd387ce607129bd290a7a89d3007c3ecc1116845dcf72a7e3a093c6e973562e464f9524970c1349e9dff4888eff9f33633a4620208c682cc372990e6cf5df14795b2dd23986f773c2f3db24bc7e2991eee8c6dbca7016d2a874d6ac50e7dfcea7caa761bb0913fa274f22c5d16eb02394598d295b946aef0df1fcce3f1aa10582bf1397592d353d260edd0610b3f80c29f1a1b23b6ed86d63be68e3fb05bf38fe20fb1d895458c5c2e7e7aea5da026631", "RustCrypto sha3 test vectors"),
+    vector(Algorithm::Blake2b512, b"abc", "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923", "RFC 7693"),
+    vector(Algorithm::Blake2s256, b"abc", "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982", "RFC 7693"),
+    vector(Algorithm::Blake3, b"", "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262", "BLAKE3 test vectors"),
+    vector(Algorithm::Ripemd160, b"", "9c1185a5c5e9fc54612808977ee8f548b2258d31", "RIPEMD-160 reference"),
+    vector(Algorithm::Ripemd160, b"abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc", "RIPEMD-160 reference"),
+    vector(Algorithm::Ripemd160, b"message digest", "5d0689ef49d2fae572b881b123a85ffa21595f36", "RIPEMD-160 reference"),
+    vector(Algorithm::Ripemd320, b"", "22d65d5661536cdc75c1fdf5c6de7b41b9f27325ebc61e8557177d705a0ec880151c3a32a00899b8", "RIPEMD-160 reference"),
+    vector(Algorithm::Ripemd320, b"abc", "de4c01b3054f8930a79d09ae738e92301e5a17085beffdc1b8d116713e74f82fa942d64cdbc4682d", "RIPEMD-160 reference"),
+    vector(Algorithm::Adler32, b"Wikipedia", "11e60398", "RFC 1950"),
+    vector(Algorithm::Xxh32, b"", "02cc5d05", "xxHash reference"),
+    vector(Algorithm::Xxh64, b"", "ef46db3751d8e999", "xxHash reference"),
+    vector(Algorithm::Xxh3_64, b"", "2d06800538d394c2", "xxHash reference"),
+    vector(Algorithm::Xxh3_128, b"", "99aa06d3014798d86001c324468d497f", "xxHash reference"),
+    vector(Algorithm::Fnv1a64, b"", "cbf29ce484222325", "FNV reference"),
+    vector(Algorithm::Fnv1a64, b"a", "af63dc4c8601ec8c", "FNV reference"),
+    vector(Algorithm::Fnv1a64, b"foobar", "85944171f73967e8", "FNV reference"),
+    vector(Algorithm::Murmur3_128, b"", "00000000000000000000000000000000", "SMHasher"),
+    vector(Algorithm::Whirlpool, b"", "19fa61d75522a4669b44e39c1d2e1726c530232130d407f89afee0964997f7a73e83be698b288febcf88e3e03c4f0757ea8964e59b63d93708b138cc42a66eb3", "ISO/IEC 10118-3"),
+    vector(Algorithm::Whirlpool, b"abc", "4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5", "ISO/IEC 10118-3"),
+    vector(Algorithm::Tiger, b"", "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3", "Tiger reference"),
+    vector(Algorithm::Tiger, b"abc", "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93", "Tiger reference"),
+    vector(Algorithm::Tiger2, b"", "4441be75f6018773c206c22745374b924aa8313fef919f41", "Tiger reference"),
+    vector(Algorithm::Streebog256, GOST_M1, "9d151eefd8590b89daa6ba6cb74af9275dd051026bb149a452fd84e5e57b5500", "GOST R 34.11-2012"),
+    vector(Algorithm::Streebog512, GOST_M1, "1b54d01a4af5b9d5cc3d86d68d285462b19abc2475222f35c085122be4ba1ffa00ad30f8767b3a82384c6574f024c311e2a481332b08ef7f41797891c1646f48", "GOST R 34.11-2012"),
+    vector(Algorithm::Sm3, b"abc", "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0", "GB/T 32905-2016"),
+    vector(Algorithm::Sm3, b"abcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcd", "debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732", "GB/T 32905-2016"),
+    vector(Algorithm::Md4, b"", "31d6cfe0d16ae931b73c59d7e0c089c0", "RFC 1320"),
+    vector(Algorithm::Md4, b"abc", "a448017aaf21d8525fc10ae87aa6729d", "RFC 1320"),
+    vector(Algorithm::Md4, b"message digest", "d9130a8164549fe818874806e1c7014b", "RFC 1320"),
+    vector(Algorithm::Md2, b"", "8350e5a3e24c153df2275c9f80692773", "RFC 1319"),
+    vector(Algorithm::Md2, b"abc", "da853b0d3f88d99b30283a69e6ded6bb", "RFC 1319"),
+    vector(Algorithm::Sure25, b"hello world", "e81cca57f2d5dbcaee196aacdd04c37e6162588470da70af3f", "sure25 reference"),
+];
+
+/// the input of the `check` of the CRC catalogue
+const CHECK: &[u8] = b"123456789";
+
+/// outcome of a known-answer test
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Outcome {
+    pub algorithm: String,
+    pub source: String,
+    /// the input with non-printable bytes escaped
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+    pub passed: bool,
+}
+
+impl Outcome {
+    fn new(
+        algorithm: Algorithm,
+        source: &str,
+        input: String,
+        expected: String,
+        actual: String,
+    ) -> Outcome {
+        Outcome {
+            algorithm: algorithm.to_string(),
+            source: source.to_string(),
+            input,
+            passed: expected == actual,
+            expected,
+            actual,
+        }
+    }
+}
+
+/// runs every vector of [`VECTORS`] the way files are digested, i.e.:
+/// through [`TableV1::cs_reader`] with a thread per algorithm of the
+/// vectors of the same input, and checks every CRC of
+/// [`Algorithm::CRC`] against the catalogue, with the default seeds
+pub fn run() -> Vec<Outcome> {
+    let mut tables = BTreeMap::<(&[u8], usize, &str), Result<TableV1, String>>::new();
+    for vector in VECTORS {
+        let input = (vector.input, vector.repeat, vector.customization);
+        if tables.contains_key(&input) {
+            continue;
+        }
+        let mut algorithms = Vec::<Algorithm>::new();
+        for other in VECTORS {
+            let same = (other.input, other.repeat, other.customization) == input;
+            if same && !algorithms.contains(&other.algorithm) {
+                algorithms.push(other.algorithm);
+            }
+        }
+        let digester = Digester::new(algorithms)
+            .with_customization(vector.customization)
+            .with_threads(0);
+        let data = vector.input.repeat(vector.repeat);
+        let table = TableV1::new(None)
+            .cs_reader(&data[..], &digester)
+            .map_err(|error| error.to_string());
+        tables.insert(input, table);
+    }
+    let mut outcomes = Vec::<Outcome>::new();
+    for vector in VECTORS {
+        let actual = match &tables[&(vector.input, vector.repeat, vector.customization)] {
+            Ok(table) => table.digest(vector.algorithm).unwrap_or_default().to_string(),
+            Err(error) => error.clone(),
+        };
+        let mut input = vector.input.escape_ascii().to_string();
+        if vector.repeat != 1 {
+            input = format!("{} (x{})", input, vector.repeat);
+        }
+        outcomes.push(Outcome::new(
+            vector.algorithm,
+            vector.source,
+            input,
+            vector.expected.to_string(),
+            actual,
+        ));
+    }
+    for algorithm in Algorithm::CRC {
+        let Some(check) = algorithm.check() else { continue };
+        let actual = Digester::new(vec![*algorithm]).digest(*algorithm, CHECK);
+        let bits = algorithm.bits().unwrap_or_default();
+        let expected = RawDigest::from_value(Some(*algorithm), check, bits).hex();
+        let source = format!("CRC catalogue {}", algorithm.catalogue().unwrap_or_default());
+        let input = CHECK.escape_ascii().to_string();
+        outcomes.push(Outcome::new(*algorithm, &source, input, expected, actual));
+    }
+    outcomes
+}
+
+/// algorithms without published known-answer vectors, e.g.: the fuzzy
+/// digests
+pub fn untested() -> Vec<Algorithm> {
+    Algorithm::every()
+        .filter(|algorithm| algorithm.check().is_none())
+        .filter(|algorithm| VECTORS.iter().all(|vector| vector.algorithm != *algorithm))
+        .collect()
+}
+
+/// totals of a [`run`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub untested: Vec<String>,
+}
+
+impl Summary {
+    pub fn new(outcomes: &[Outcome]) -> Summary {
+        let passed = outcomes.iter().filter(|outcome| outcome.passed).count();
+        Summary {
+            passed,
+            failed: outcomes.len() - passed,
+            untested: untested().iter().map(Algorithm::to_string).collect(),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tlsh2::Tlsh128_1 as Tlsh;
+
+use crate::{Algorithm, TableV1};
+
+/// default minimum `ssdeep` score, from 0 to 100, of similar entries
+pub const SSDEEP_THRESHOLD: u32 = 50;
+/// default maximum TLSH distance of similar entries, 0 means identical
+pub const TLSH_DISTANCE: i32 = 100;
+
+/// scores of the fuzzy digests of two entries, either of which is
+/// [`None`] when one of the entries lacks or cannot be compared by
+/// that digest
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Similarity {
+    pub left: String,
+    pub right: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssdeep: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tlsh: Option<i32>,
+}
+
+impl Similarity {
+    pub fn between(left: (&str, &TableV1), right: (&str, &TableV1)) -> Similarity {
+        let (left, ltable) = left;
+        let (right, rtable) = right;
+        let digests = |algorithm: Algorithm| ltable.digest(algorithm).zip(rtable.digest(algorithm));
+        Similarity {
+            left: left.to_string(),
+            right: right.to_string(),
+            ssdeep: digests(Algorithm::Ssdeep).and_then(|(l, r)| ssdeep_score(l, r)),
+            tlsh: digests(Algorithm::Tlsh).and_then(|(l, r)| tlsh_distance(l, r)),
+        }
+    }
+
+    /// whether either the `ssdeep` score reaches the threshold or the
+    /// TLSH distance is within the given distance
+    pub fn matches(&self, threshold: u32, distance: i32) -> bool {
+        self.ssdeep.is_some_and(|score| score >= threshold)
+            || self.tlsh.is_some_and(|tlsh| tlsh <= distance)
+    }
+}
+
+/// `ssdeep` score of two context-triggered piecewise hashes, from 0
+/// for no similarity to 100
+pub fn ssdeep_score(left: &str, right: &str) -> Option<u32> {
+    ssdeep::compare(left, right).ok()
+}
+
+/// TLSH distance including the length of the data, from 0 for
+/// identical digests, [`None`] for `TNULL` digests
+pub fn tlsh_distance(left: &str, right: &str) -> Option<i32> {
+    let left = Tlsh::from_str(left).ok()?;
+    let right = Tlsh::from_str(right).ok()?;
+    Some(left.diff(&right, true))
+}
+
+/// compares every pair of the given labeled entries and keeps the
+/// ones which [`Similarity::matches`]
+pub fn similar(entries: &[(String, TableV1)], threshold: u32, distance: i32) -> Vec<Similarity> {
+    let mut pairs = Vec::<Similarity>::new();
+    for (index, (left, ltable)) in entries.iter().enumerate() {
+        for (right, rtable) in &entries[index + 1..] {
+            let similarity = Similarity::between((left, ltable), (right, rtable));
+            if similarity.matches(threshold, distance) {
+                pairs.push(similarity);
+            }
+        }
+    }
+    pairs
+}
@@ -0,0 +1,41 @@
+use iocore::{Path, PathDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// what changes about a file when it is written to, taken before and
+/// after digesting it to tell whether it changed in between, see
+/// [`crate::TableV1::unstable`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Stat {
+    pub size: u64,
+    pub modified: PathDateTime,
+    /// when the inode last changed, e.g.: by a write or a rename
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed: Option<PathDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inode: Option<u64>,
+}
+
+impl Stat {
+    /// stat of the file a path refers to, following symlinks
+    pub fn of(path: &Path) -> Result<Stat, Error> {
+        let metadata = std::fs::metadata(path.to_path_buf())
+            .map_err(|e| Error::Error(format!("stat {}: {}", path, e)))?;
+        #[cfg(unix)]
+        let (changed, inode) = {
+            use std::os::unix::fs::MetadataExt;
+            let changed =
+                PathDateTime::from_timestamp(metadata.ctime(), metadata.ctime_nsec() as u32);
+            (Some(changed), Some(metadata.ino()))
+        };
+        #[cfg(not(unix))]
+        let (changed, inode) = (None, None);
+        Ok(Stat {
+            size: metadata.len(),
+            modified: PathDateTime::from(metadata.modified()?),
+            changed,
+            inode,
+        })
+    }
+}
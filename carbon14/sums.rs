@@ -0,0 +1,38 @@
+use crate::{Algorithm, Error};
+
+/// lines of the checksum lists of coreutils, which `sha256sum -c` and
+/// the like verify, of a single algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sums {
+    /// `<digest>  <filename>` as written by `sha256sum`
+    Gnu,
+    /// `<TAG> (<filename>) = <digest>` as written by `sha256sum --tag`
+    /// and the `sha256` of the BSDs, see [`Algorithm::tag`]
+    Bsd,
+}
+
+impl Sums {
+    /// the line of a file, terminator included. Filenames holding a
+    /// backslash, a line feed or a carriage return have those escaped by
+    /// a backslash and the line is then prefixed with a backslash as
+    /// coreutils does
+    pub fn line(
+        &self,
+        algorithm: Algorithm,
+        filename: &str,
+        digest: &str,
+    ) -> Result<String, Error> {
+        let escaped = filename.contains(['\\', '\n', '\r']);
+        let filename = filename.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r");
+        let prefix = if escaped { "\\" } else { "" };
+        Ok(match self {
+            Sums::Gnu => format!("{}{}  {}\n", prefix, digest, filename),
+            Sums::Bsd => {
+                let tag = algorithm.tag().ok_or_else(|| {
+                    Error::Error(format!("{} has no tag of BSD-style checksum lists", algorithm))
+                })?;
+                format!("{}{} ({}) = {}\n", prefix, tag, filename, digest)
+            },
+        })
+    }
+}
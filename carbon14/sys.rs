@@ -1,17 +1,18 @@
 use copypasta::{ClipboardContext, ClipboardProvider};
+use iocore::Path;
 use std::io::{stdin, IsTerminal};
 use std::io::read_to_string;
 
 
 pub fn clipboard_ops() -> Option<String> {
-    ClipboardContext::new().map(|c| Some(c)).unwrap_or(None).map(|mut c| c.get_contents().map(|s| Some(s)).unwrap_or(None)).unwrap_or(None)
+    ClipboardContext::new().map(Some).unwrap_or(None).map(|mut c| c.get_contents().map(Some).unwrap_or(None)).unwrap_or(None)
 }
 
 pub fn stdin_ops() -> Option<String> {
     if stdin().is_terminal() {
         None }
     else {
-        read_to_string(stdin().lock()).map(|s| Some(s)).unwrap_or(None)
+        read_to_string(stdin().lock()).map(Some).unwrap_or(None)
     }
 }
 
@@ -26,3 +27,16 @@ pub fn stdin_lines() -> Option<Vec<String>> {
 pub fn oplines(ops: Option<String>) -> Option<Vec<String>> {
     ops.map(|s| s.lines().map(String::from).collect::<Vec<String>>()).filter(|t| !t.is_empty())
 }
+
+/// canonical path relative to the current directory when within it, or
+/// else absolute, comparing whole components unlike
+/// [`Path::relative_to_cwd`] which spells some paths outside of the
+/// current directory as another path
+pub fn relative_to_cwd(path: &Path) -> Path {
+    let canonical = path.try_canonicalize();
+    let cwd = Path::cwd().try_canonicalize().to_path_buf();
+    match canonical.to_path_buf().strip_prefix(cwd) {
+        Ok(relative) if !relative.as_os_str().is_empty() => Path::raw(relative.display()),
+        _ => canonical,
+    }
+}
@@ -1,21 +1,96 @@
 use iocore::{Path, PathDateTime};
-use adler32::adler32;
+use std::collections::BTreeMap;
+use std::hash::Hasher;
+use std::io::{Read, Seek, SeekFrom};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::mpsc::{Sender, SyncSender, channel, sync_channel};
+use std::thread::{JoinHandle, spawn};
+
+use adler32::RollingAdler32;
+pub use blake2::{Blake2b512, Blake2s256};
+use blake3::Hasher as Blake3;
 use crc::{
-    CRC_3_GSM, CRC_4_G_704, CRC_5_G_704, CRC_6_CDMA2000_A, CRC_6_CDMA2000_B, CRC_6_GSM, CRC_8_LTE,
-    CRC_11_FLEXRAY, CRC_16_OPENSAFETY_A, CRC_16_OPENSAFETY_B, CRC_16_PROFIBUS, CRC_16_TELEDISK,
-    CRC_16_USB, CRC_16_XMODEM, CRC_24_BLE, CRC_24_OPENPGP, CRC_32_BZIP2, CRC_32_ISCSI,
-    CRC_32_ISO_HDLC, CRC_32_JAMCRC, CRC_32_MPEG_2, CRC_40_GSM, CRC_64_ECMA_182, CRC_64_GO_ISO,
-    CRC_64_MS, CRC_64_REDIS, CRC_64_WE, CRC_64_XZ, CRC_82_DARC, Crc,
+    Crc, CRC_10_ATM, CRC_10_CDMA2000, CRC_10_GSM, CRC_11_FLEXRAY, CRC_11_UMTS, CRC_12_CDMA2000,
+    CRC_12_DECT, CRC_12_GSM, CRC_12_UMTS, CRC_13_BBC, CRC_14_DARC, CRC_14_GSM, CRC_15_CAN,
+    CRC_15_MPT1327, CRC_16_ARC, CRC_16_CDMA2000, CRC_16_CMS, CRC_16_DDS_110, CRC_16_DECT_R,
+    CRC_16_DECT_X, CRC_16_DNP, CRC_16_EN_13757, CRC_16_GENIBUS, CRC_16_GSM, CRC_16_IBM_3740,
+    CRC_16_IBM_SDLC, CRC_16_ISO_IEC_14443_3_A, CRC_16_KERMIT, CRC_16_LJ1200, CRC_16_M17,
+    CRC_16_MAXIM_DOW, CRC_16_MCRF4XX, CRC_16_MODBUS, CRC_16_NRSC_5, CRC_16_OPENSAFETY_A,
+    CRC_16_OPENSAFETY_B, CRC_16_PROFIBUS, CRC_16_RIELLO, CRC_16_SPI_FUJITSU, CRC_16_T10_DIF,
+    CRC_16_TELEDISK, CRC_16_TMS37157, CRC_16_UMTS, CRC_16_USB, CRC_16_XMODEM, CRC_17_CAN_FD,
+    CRC_21_CAN_FD, CRC_24_BLE, CRC_24_FLEXRAY_A, CRC_24_FLEXRAY_B, CRC_24_INTERLAKEN, CRC_24_LTE_A,
+    CRC_24_LTE_B, CRC_24_OPENPGP, CRC_24_OS_9, CRC_30_CDMA, CRC_31_PHILIPS, CRC_32_AIXM,
+    CRC_32_AUTOSAR, CRC_32_BASE91_D, CRC_32_BZIP2, CRC_32_CD_ROM_EDC, CRC_32_CKSUM, CRC_32_ISCSI,
+    CRC_32_ISO_HDLC, CRC_32_JAMCRC, CRC_32_MEF, CRC_32_MPEG_2, CRC_32_XFER, CRC_3_GSM, CRC_3_ROHC,
+    CRC_40_GSM, CRC_4_G_704, CRC_4_INTERLAKEN, CRC_5_EPC_C1G2, CRC_5_G_704, CRC_5_USB,
+    CRC_64_ECMA_182, CRC_64_GO_ISO, CRC_64_MS, CRC_64_NVME, CRC_64_REDIS, CRC_64_WE, CRC_64_XZ,
+    CRC_6_CDMA2000_A, CRC_6_CDMA2000_B, CRC_6_DARC, CRC_6_GSM, CRC_6_G_704, CRC_7_MMC, CRC_7_ROHC,
+    CRC_7_UMTS, CRC_82_DARC, CRC_8_AUTOSAR, CRC_8_BLUETOOTH, CRC_8_CDMA2000, CRC_8_DARC,
+    CRC_8_DVB_S2, CRC_8_GSM_A, CRC_8_GSM_B, CRC_8_HITAG, CRC_8_I_432_1, CRC_8_I_CODE, CRC_8_LTE,
+    CRC_8_MAXIM_DOW, CRC_8_MIFARE_MAD, CRC_8_NRSC_5, CRC_8_OPENSAFETY, CRC_8_ROHC, CRC_8_SAE_J1850,
+    CRC_8_SMBUS, CRC_8_TECH_3250, CRC_8_WCDMA,
 };
-pub use sha::sha1::Sha1;
-pub use sha::utils::{Digest, DigestExt};
+pub use sha1::Sha1;
+pub use sha2::Digest;
 pub use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
-use sha2::Digest as Sha2Digest;
 pub use sha3::{Keccak224, Keccak256, Keccak256Full, Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+pub use sha3::{CShake128, CShake128Core, CShake256, CShake256Core, Shake128, Shake256};
+use sha3::digest::{ExtendableOutput, XofReader};
 pub use md5::compute as md5_compute;
+use md5::Context as Md5;
 use serde::{Deserialize, Serialize};
 use ripemd::{Ripemd160, Ripemd320};
+use md2::Md2;
+use md4::Md4;
+use sm3::Sm3;
+use streebog::{Streebog256, Streebog512};
+use tiger::{Tiger, Tiger2};
+use whirlpool::Whirlpool;
 use sure25::Hasher as Sure25;
+use fnv::FnvHasher;
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::{Mac, SimpleHmac};
+use mur3::Hasher128 as Murmur3;
+use ssdeep::Generator as Ssdeep;
+use tlsh2::TlshDefaultBuilder as Tlsh;
+use xxhash_rust::xxh3::Xxh3;
+use xxhash_rust::xxh32::Xxh32;
+use xxhash_rust::xxh64::Xxh64;
+
+use crate::Error;
+use crate::encoding::{Encoding, RawDigest};
+use crate::key::Key;
+use crate::checkpoint::{Checkpoint, CrcState};
+use crate::resumable::{self, BlockDigest, Compress, Sponge};
+use crate::rocksoft::{Rocksoft, RocksoftDigest};
+use crate::stat::Stat;
+use crate::sys::relative_to_cwd;
+
+/// fields of [`TableV1`] other than the digests of [`Algorithm`]
+const FIELDS: &[&str] = &[
+    "filename",
+    "accessed",
+    "modified",
+    "created",
+    "size",
+    "data",
+    "key",
+    "keyed",
+    "seeds",
+    "lengths",
+    "customization",
+    "encoding",
+    "range",
+    "piecewise",
+    "unstable",
+    "before",
+    "after",
+];
+
+/// size of the chunks fed to every hasher by [`TableV1::from_reader`]
+/// and [`TableV1::cs`].
+pub const CHUNK_SIZE: usize = 0x10000;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TableV1 {
@@ -27,75 +102,381 @@ pub struct TableV1 {
     modified: Option<PathDateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     created: Option<PathDateTime>,
+    /// size of the file in bytes when its table was made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha512: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha224: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha384: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha512_224: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha512_256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     keccak256_full: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     keccak256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     keccak224: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha3_224: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha3_256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha3_384: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sha3_512: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shake128: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shake256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cshake128: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cshake256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blake2b512: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blake2s256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blake3: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     adler32: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc3_gsm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc3_rohc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc4_g704: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc4_interlaken: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc5_epc_c1g2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc5_g704: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc5_usb: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc6_gsm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc7_mmc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc7_rohc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc7_umts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_autosar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_bluetooth: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_cdma2000: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_darc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_dvb_s2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_gsm_a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_gsm_b: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_hitag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_i432_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_i_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc6_cdma_2000a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc6_cdma_2000b: Option<String>,
-    // crc5_g_704: Option<String>,
-    // crc5_usb: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc6_darc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc6_g704: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc8_lte: Option<String>,
-    // Crc8_WCDMA: Option<String>,
-    // Crc8_SMBUS: Option<String>,
-    // Crc8_OPENSAFETY: Option<String>,
-    // Crc8_HITAG: Option<String>,
-    // Crc1_0_ATM: Option<String>,
-    // Crc1_0_CDMA2000: Option<String>,
-    // Crc1_0_GSM: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_maxim_dow: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_mifare_mad: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_nrsc5: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_opensafety: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_rohc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_sae_j1850: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_smbus: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_tech3250: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc8_wcdma: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc10_atm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc10_cdma2000: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc10_gsm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc11_flexray: Option<String>,
-    // Crc1_1_UMTS: Option<String>,
-    // Crc1_6_GSM: Option<String>,
-    // Crc1_6_ISO_IEC_14443_3_A: Option<String>,
-    // Crc1_6_MAXIM_DOW: Option<String>,
-    // Crc1_6_MCRF4XX: Option<String>,
-    // Crc1_6_MODBUS: Option<String>,
-    // Crc1_6_NRSC_5: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc11_umts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc12_cdma2000: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc12_dect: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc12_gsm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc12_umts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc13_bbc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc14_darc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc14_gsm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc15_can: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc15_mpt1327: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_arc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_cdma2000: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_cms: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_dds110: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_dect_r: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_dect_x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_dnp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_en13757: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_genibus: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_gsm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_ibm3740: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_ibm_sdlc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_iso_iec14443_3_a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_kermit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_lj1200: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_m17: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_maxim_dow: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_mcrf4xx: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_modbus: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_nrsc5: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc16_opensafety_a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc16_opensafety_b: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc16_profibus: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_riello: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_spi_fujitsu: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_t10_dif: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc16_teledisk: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_tms37157: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc16_umts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc16_usb: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc16_xmodem: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc17_can_fd: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc21_can_fd: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc24_ble: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc24_flexray_a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc24_flexray_b: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc24_interlaken: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc24_lte_a: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc24_lte_b: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc24_openpgp: Option<String>,
-    // Crc3_2_AIXM: Option<String>,
-    // Crc3_2_AUTOSAR: Option<String>,
-    // Crc3_2_BASE91_D: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc24_os9: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc30_cdma: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc31_philips: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_aixm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_autosar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_base91_d: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc32_bzip2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_cd_rom_edc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_cksum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc32_iscsi: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc32_isohdlc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc32_jamcrc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_mef: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc32_mpeg2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_xfer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc40_gsm: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc64_ecma182: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc64_goiso: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc64_ms: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc64_nvme: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc64_redis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc64_we: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc64_xz: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     crc82_darc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     md5: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     ripemd160: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     ripemd320: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    whirlpool: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tiger: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tiger2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    streebog256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    streebog512: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sm3: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    md4: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    md2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     sure25: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xxh32: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xxh64: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xxh3_64: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xxh3_128: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fnv1a_64: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    murmur3_128: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ssdeep: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tlsh: Option<String>,
+    /// digests of the [`Rocksoft`] CRCs given to [`Digester::with_crc`]
+    #[serde(flatten)]
+    crcs: BTreeMap<String, String>,
+    /// id of the [`Key`] of the `keyed` digests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+    /// HMAC and keyed BLAKE3 digests by algorithm, see [`Digester::with_key`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keyed: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seeds: Option<BTreeMap<String, u64>>,
+    /// output lengths in bytes of the extendable-output functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lengths: Option<BTreeMap<String, usize>>,
+    /// customization string of the cSHAKE digests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customization: Option<String>,
+    /// encoding of the digests other than hex, see [`TableV1::encoded`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<Encoding>,
+    /// the bytes digested when only part of the data was, see
+    /// [`Digester::with_range`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<ByteRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    piecewise: Option<Piecewise>,
+    /// set when the file kept changing while it was digested, along
+    /// with its stat before and after the last attempt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unstable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<Stat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<Stat>,
+}
+
+/// window of the data to digest, e.g.: a partition within a raw
+/// image or the payload after a header, up to the end of the data
+/// when `length` is [`None`]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteRange {
+    pub offset: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+}
+
+/// digests of consecutive pieces of the data, in the manner of
+/// `hashdeep -p` and the `hashwindow` of `dcfldd`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Piecewise {
+    pub algorithm: String,
+    pub size: u64,
+    pub pieces: Vec<Piece>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Piece {
+    pub offset: u64,
+    pub length: u64,
+    pub digest: String,
 }
 
 impl TableV1 {
@@ -103,7 +484,7 @@ impl TableV1 {
         let (filename, accessed, modified, created) = match meta {
             Some(ref f) => match Path::raw(f).timestamps() {
                 Ok(timestamps) => (
-                    Some(timestamps.path.relative_to_cwd().to_string()),
+                    Some(relative_to_cwd(&timestamps.path).to_string()),
                     Some(timestamps.accessed),
                     Some(timestamps.modified),
                     Some(timestamps.created),
@@ -115,42 +496,131 @@ impl TableV1 {
             },
             None => (None, None, None, None),
         };
+        let size = match (&meta, &filename) {
+            (Some(f), Some(_)) => Path::raw(f).size().ok().map(|size| size.as_u64()),
+            _ => None,
+        };
         let data = meta.xor(filename.clone());
-        return TableV1 {
-            filename: filename,
-            accessed: accessed,
-            modified: modified,
-            created: created,
-            data: data,
+        TableV1 {
+            filename,
+            accessed,
+            modified,
+            created,
+            size,
+            data,
             md5: None,
             sha1: None,
             adler32: None,
             crc3_gsm: None,
+            crc3_rohc: None,
             crc4_g704: None,
+            crc4_interlaken: None,
+            crc5_epc_c1g2: None,
             crc5_g704: None,
+            crc5_usb: None,
             crc6_gsm: None,
+            crc7_mmc: None,
+            crc7_rohc: None,
+            crc7_umts: None,
+            crc8_autosar: None,
+            crc8_bluetooth: None,
+            crc8_cdma2000: None,
+            crc8_darc: None,
+            crc8_dvb_s2: None,
+            crc8_gsm_a: None,
+            crc8_gsm_b: None,
+            crc8_hitag: None,
+            crc8_i432_1: None,
+            crc8_i_code: None,
             crc6_cdma_2000a: None,
             crc6_cdma_2000b: None,
+            crc6_darc: None,
+            crc6_g704: None,
             crc8_lte: None,
+            crc8_maxim_dow: None,
+            crc8_mifare_mad: None,
+            crc8_nrsc5: None,
+            crc8_opensafety: None,
+            crc8_rohc: None,
+            crc8_sae_j1850: None,
+            crc8_smbus: None,
+            crc8_tech3250: None,
+            crc8_wcdma: None,
+            crc10_atm: None,
+            crc10_cdma2000: None,
+            crc10_gsm: None,
             crc11_flexray: None,
+            crc11_umts: None,
+            crc12_cdma2000: None,
+            crc12_dect: None,
+            crc12_gsm: None,
+            crc12_umts: None,
+            crc13_bbc: None,
+            crc14_darc: None,
+            crc14_gsm: None,
+            crc15_can: None,
+            crc15_mpt1327: None,
+            crc16_arc: None,
+            crc16_cdma2000: None,
+            crc16_cms: None,
+            crc16_dds110: None,
+            crc16_dect_r: None,
+            crc16_dect_x: None,
+            crc16_dnp: None,
+            crc16_en13757: None,
+            crc16_genibus: None,
+            crc16_gsm: None,
+            crc16_ibm3740: None,
+            crc16_ibm_sdlc: None,
+            crc16_iso_iec14443_3_a: None,
+            crc16_kermit: None,
+            crc16_lj1200: None,
+            crc16_m17: None,
+            crc16_maxim_dow: None,
+            crc16_mcrf4xx: None,
+            crc16_modbus: None,
+            crc16_nrsc5: None,
             crc16_teledisk: None,
+            crc16_tms37157: None,
+            crc16_umts: None,
             crc16_opensafety_a: None,
             crc16_opensafety_b: None,
             crc16_profibus: None,
+            crc16_riello: None,
+            crc16_spi_fujitsu: None,
+            crc16_t10_dif: None,
             crc16_usb: None,
             crc16_xmodem: None,
+            crc17_can_fd: None,
+            crc21_can_fd: None,
             crc24_ble: None,
+            crc24_flexray_a: None,
+            crc24_flexray_b: None,
+            crc24_interlaken: None,
+            crc24_lte_a: None,
+            crc24_lte_b: None,
             crc24_openpgp: None,
+            crc24_os9: None,
+            crc30_cdma: None,
+            crc31_philips: None,
+            crc32_aixm: None,
+            crc32_autosar: None,
+            crc32_base91_d: None,
             crc32_bzip2: None,
+            crc32_cd_rom_edc: None,
+            crc32_cksum: None,
             crc32_iscsi: None,
             crc32_jamcrc: None,
+            crc32_mef: None,
             crc32_isohdlc: None,
             crc32_mpeg2: None,
+            crc32_xfer: None,
             crc40_gsm: None,
             crc64_xz: None,
             crc64_redis: None,
             crc64_goiso: None,
             crc64_ms: None,
+            crc64_nvme: None,
             crc64_we: None,
             crc64_ecma182: None,
             crc82_darc: None,
@@ -164,224 +634,3078 @@ impl TableV1 {
             sha3_256: None,
             sha3_384: None,
             sha3_512: None,
+            shake128: None,
+            shake256: None,
+            cshake128: None,
+            cshake256: None,
+            blake2b512: None,
+            blake2s256: None,
+            blake3: None,
             keccak224: None,
             keccak256: None,
             keccak256_full: None,
             ripemd160: None,
             ripemd320: None,
+            whirlpool: None,
+            tiger: None,
+            tiger2: None,
+            streebog256: None,
+            streebog512: None,
+            sm3: None,
+            md4: None,
+            md2: None,
             sure25: None,
-        };
+            xxh32: None,
+            xxh64: None,
+            xxh3_64: None,
+            xxh3_128: None,
+            fnv1a_64: None,
+            murmur3_128: None,
+            ssdeep: None,
+            tlsh: None,
+            crcs: BTreeMap::new(),
+            key: None,
+            keyed: None,
+            seeds: None,
+            lengths: None,
+            customization: None,
+            encoding: None,
+            range: None,
+            piecewise: None,
+            unstable: None,
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn from_reader(meta: Option<String>, reader: impl Read) -> Result<TableV1, Error> {
+        TableV1::new(meta).cs_reader(reader, &Digester::default())
     }
+
     pub fn cs(&mut self, data: Vec<u8>) -> TableV1 {
-        let data = data.to_vec();
-        let mut sha3 = Sha3_224::new();
-        sha3.update(data.as_slice());
-        let sha3_224 = hex::encode(sha3.finalize());
+        let mut hashers = Digester::default().hashers();
+        for chunk in data.chunks(CHUNK_SIZE) {
+            hashers.update(chunk);
+        }
+        hashers.finalize(self);
+        self.clone()
+    }
+
+    /// digests the data read, skipping the offset of the range of the
+    /// digester, if any, by reading as much
+    pub fn cs_reader(
+        &mut self,
+        mut reader: impl Read,
+        digester: &Digester,
+    ) -> Result<TableV1, Error> {
+        if let Some(range) = digester.range {
+            let skipped =
+                std::io::copy(&mut (&mut reader).take(range.offset), &mut std::io::sink())?;
+            if skipped < range.offset {
+                return Err(Error::Error(format!(
+                    "offset {} is beyond the end of the data at {}",
+                    range.offset, skipped
+                )));
+            }
+        }
+        self.cs_window(reader, digester)
+    }
+
+    /// digests the data read as [`TableV1::cs_reader`] does, skipping
+    /// the offset of the range of the digester by seeking, e.g.: within
+    /// a file or a block device
+    pub fn cs_seeker(
+        &mut self,
+        mut reader: impl Read + Seek,
+        digester: &Digester,
+    ) -> Result<TableV1, Error> {
+        if let Some(range) = digester.range {
+            let end = reader.seek(SeekFrom::End(0))?;
+            if range.offset > end {
+                return Err(Error::Error(format!(
+                    "offset {} is beyond the end of the data at {}",
+                    range.offset, end
+                )));
+            }
+            reader.seek(SeekFrom::Start(range.offset))?;
+        }
+        self.cs_window(reader, digester)
+    }
+
+    /// digests the data of `file` as [`TableV1::cs_seeker`] does while
+    /// saving a [`Checkpoint`] at `path` every `interval` bytes,
+    /// starting over from the given checkpoint if any, then removes the
+    /// checkpoint once all of the data is digested. Only whole data can
+    /// be checkpointed, see [`Digester::resumable`], and only a
+    /// checkpoint of the very same file, as told by its canonical path
+    /// and [`Stat`], is resumed from.
+    pub fn cs_resumable(
+        &mut self,
+        mut reader: impl Read + Seek,
+        digester: &Digester,
+        file: &Path,
+        path: &Path,
+        interval: u64,
+        checkpoint: Option<Checkpoint>,
+    ) -> Result<TableV1, Error> {
+        digester.resumable()?;
+        let size = reader.seek(SeekFrom::End(0))?;
+        let identity = (file.try_canonicalize().to_string(), Stat::of(file)?);
+        let (hashers, offset) = match checkpoint {
+            Some(checkpoint) => {
+                if (&checkpoint.path, &checkpoint.stat) != (&identity.0, &identity.1) {
+                    return Err(Error::Error(format!(
+                        "checkpoint {} is of {} as it was then, not of {} as it is now",
+                        path, checkpoint.path, identity.0
+                    )));
+                }
+                if checkpoint.stat.size != size || checkpoint.offset > size {
+                    return Err(Error::Error(format!(
+                        "checkpoint {} at {} of {} bytes does not match the {} bytes of data",
+                        path, checkpoint.offset, checkpoint.stat.size, size
+                    )));
+                }
+                (digester.resume(&checkpoint)?, checkpoint.offset)
+            },
+            None => (digester.resumable_hashers(), 0),
+        };
+        reader.seek(SeekFrom::Start(offset))?;
+        let checkpoints = Checkpoints {
+            path,
+            interval: interval.max(1),
+            file: identity,
+            crcs: digester.crcs(),
+            next: offset.saturating_add(interval.max(1)),
+        };
+        let table = self.cs_stream(reader, digester, hashers, offset, Some(checkpoints))?;
+        if path.exists() {
+            path.delete()?;
+        }
+        Ok(table)
+    }
+
+    /// digests the data from the offset of the range of the digester
+    /// onwards
+    fn cs_window(&mut self, reader: impl Read, digester: &Digester) -> Result<TableV1, Error> {
+        self.cs_stream(reader, digester, digester.hashers(), 0, None)
+    }
+
+    /// feeds the hashers `total` bytes into the data with the rest of it
+    fn cs_stream(
+        &mut self,
+        reader: impl Read,
+        digester: &Digester,
+        mut hashers: Hashers,
+        mut total: u64,
+        mut checkpoints: Option<Checkpoints>,
+    ) -> Result<TableV1, Error> {
+        let range = digester.range;
+        let mut reader = reader.take(range.and_then(|range| range.length).unwrap_or(u64::MAX));
+        let mut pieces = digester.pieces();
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        loop {
+            let length = read_chunk(&mut reader, &mut chunk)?;
+            if length > 0 {
+                hashers.update(&chunk[..length]);
+                if let Some(pieces) = pieces.as_mut() {
+                    pieces.update(&chunk[..length], digester);
+                }
+            }
+            total += length as u64;
+            if length < CHUNK_SIZE {
+                break;
+            }
+            if let Some(checkpoints) = checkpoints.as_mut() {
+                checkpoints.save(&hashers, total)?;
+            }
+        }
+        if let Some(length) = range.and_then(|range| range.length) {
+            if total < length {
+                return Err(Error::Error(format!(
+                    "range of {} bytes ends {} bytes beyond the end of the data",
+                    length,
+                    length - total
+                )));
+            }
+        }
+        hashers.finalize(self);
+        self.key = digester.key().map(|key| key.id().to_string());
+        let seeds = digester.custom_seeds();
+        self.seeds = (!seeds.is_empty()).then_some(seeds);
+        let lengths = digester.xof_lengths();
+        self.lengths = (!lengths.is_empty()).then_some(lengths);
+        self.customization = digester
+            .algorithms
+            .iter()
+            .any(|algorithm| matches!(algorithm, Algorithm::Cshake128 | Algorithm::Cshake256))
+            .then(|| digester.customization.clone());
+        self.piecewise = pieces.map(|pieces| pieces.finalize(digester));
+        self.range = range.map(|range| ByteRange {
+            offset: range.offset,
+            length: Some(total),
+        });
+        Ok(self.clone())
+    }
+
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    /// size in bytes of the file, which may differ from the amount of
+    /// bytes digested, see [`TableV1::range`]
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    pub fn data(&self) -> Option<&str> {
+        self.data.as_deref()
+    }
+
+    pub fn piecewise(&self) -> Option<&Piecewise> {
+        self.piecewise.as_ref()
+    }
+
+    /// whether the file changed while it was digested, in which case
+    /// the digests describe neither its contents before nor after
+    pub fn unstable(&self) -> bool {
+        self.unstable.unwrap_or(false)
+    }
+
+    /// the stat of the file before and after digesting it, when it
+    /// changed in between
+    pub fn stats(&self) -> Option<(&Stat, &Stat)> {
+        self.before.as_ref().zip(self.after.as_ref())
+    }
+
+    /// marks the table as that of a file which changed from `before`
+    /// to `after` while it was digested
+    pub fn mark_unstable(&mut self, before: Stat, after: Stat) {
+        self.unstable = Some(true);
+        self.before = Some(before);
+        self.after = Some(after);
+    }
+
+    /// digests of the custom CRCs by name
+    pub fn crcs(&self) -> &BTreeMap<String, String> {
+        &self.crcs
+    }
+
+    /// the bytes digested when only part of the data was
+    pub fn range(&self) -> Option<ByteRange> {
+        self.range
+    }
+
+    /// encoding of the digests, [`Encoding::Hex`] unless re-encoded
+    pub fn encoding(&self) -> Encoding {
+        self.encoding.unwrap_or_default()
+    }
+
+    /// the table with its hex digests rendered in another encoding,
+    /// except for the fuzzy digests which are not mere bytes and the
+    /// keyed digests which [`Encoding::Sri`] and [`Encoding::Multihash`]
+    /// would otherwise mistake for the plain digests of their algorithm
+    pub fn encoded(&self, encoding: Encoding) -> Result<TableV1, Error> {
+        if self.encoding() != Encoding::Hex {
+            return Err(Error::Error(format!(
+                "{}: already encoded in {}",
+                self.filename.clone().unwrap_or_default(),
+                self.encoding()
+            )));
+        }
+        if encoding == Encoding::Hex {
+            return Ok(self.clone());
+        }
+        // digests without a multihash code stay as they are rather than
+        // re-rendered from their bytes, which may take an extra digit
+        let encode = |algorithm: Option<Algorithm>, digest: &str| {
+            let raw = RawDigest::from_hex(algorithm, digest)?;
+            Ok::<String, Error>(match (encoding, raw.multihash()) {
+                (Encoding::Multihash, None) => digest.to_string(),
+                _ => raw.encode(encoding),
+            })
+        };
+        let mut table = self.clone();
+        for algorithm in Algorithm::every().filter(|a| !Algorithm::FUZZY.contains(a)) {
+            if let Some(digest) = self.digest(algorithm) {
+                table.set(algorithm, encode(Some(algorithm), digest)?);
+            }
+        }
+        for digest in table.crcs.values_mut() {
+            *digest = encode(None, digest)?;
+        }
+        for digest in table.keyed.iter_mut().flat_map(|keyed| keyed.values_mut()) {
+            *digest = encode(None, digest)?;
+        }
+        if let Some(piecewise) = table.piecewise.as_mut() {
+            let algorithm = piecewise.algorithm.parse::<Algorithm>().ok();
+            for piece in piecewise.pieces.iter_mut() {
+                piece.digest = encode(algorithm, &piece.digest)?;
+            }
+        }
+        table.encoding = Some(encoding);
+        Ok(table)
+    }
+
+    /// names of the columns of the tables digested by `digester` when
+    /// written as rows, e.g.: by [`crate::Delimited::document`], which
+    /// are always in this order:
+    ///
+    /// - `filename`, `data`, `accessed`, `modified`, `created` and `size`
+    /// - the digests of the selected algorithms in the order of
+    ///   [`Algorithm::every`]
+    /// - the custom CRCs in the order they were given
+    /// - `key` and `keyed.<algorithm>` of each keyed algorithm
+    /// - `range.offset` and `range.length` of byte ranges
+    /// - `unstable`
+    ///
+    /// The pieces of piecewise digests and the fields which hold the
+    /// same value for every table of a run, e.g.: `seeds`, are left out.
+    pub fn columns(digester: &Digester) -> Vec<String> {
+        let mut columns = ["filename", "data", "accessed", "modified", "created", "size"]
+            .map(String::from)
+            .to_vec();
+        columns.extend(
+            Algorithm::every()
+                .filter(|algorithm| digester.algorithms().contains(algorithm))
+                .map(|algorithm| algorithm.to_string()),
+        );
+        columns.extend(digester.crcs().iter().map(|crc| crc.name.clone()));
+        if digester.key().is_some() {
+            columns.push("key".to_string());
+            columns.extend(digester.keyed().iter().map(|algorithm| format!("keyed.{}", algorithm)));
+        }
+        if digester.range().is_some() {
+            columns.extend(["range.offset", "range.length"].map(String::from));
+        }
+        columns.push("unstable".to_string());
+        columns
+    }
 
-        let mut sha3_256 = Sha3_256::new();
-        sha3_256.update(data.as_slice());
-        let sha3_256 = hex::encode(sha3_256.finalize());
+    /// the table with its hex digests checked to take as many digits
+    /// as the bits of their algorithm, see [`Algorithm::bits`], or
+    /// re-rendered in that many digits when `padded`, i.e.: to read the
+    /// tables of older versions which padded some digests to the width
+    /// of another algorithm, see [`RawDigest::from_padded_hex`]
+    pub fn canonical(&self, padded: bool) -> Result<TableV1, Error> {
+        if self.encoding() != Encoding::Hex {
+            return Ok(self.clone());
+        }
+        let canonical = |algorithm: Option<Algorithm>, digest: &str| {
+            match algorithm {
+                Some(algorithm) if padded => RawDigest::from_padded_hex(algorithm, digest),
+                _ => RawDigest::from_hex(algorithm, digest),
+            }
+            .map(|raw| raw.hex())
+            .map_err(|e| {
+                Error::Error(format!("{}: {}", self.filename.clone().unwrap_or_default(), e))
+            })
+        };
+        let mut table = self.clone();
+        for algorithm in Algorithm::every().filter(|a| !Algorithm::FUZZY.contains(a)) {
+            if let Some(digest) = self.digest(algorithm) {
+                table.set(algorithm, canonical(Some(algorithm), digest)?);
+            }
+        }
+        if let Some(piecewise) = table.piecewise.as_mut() {
+            let algorithm = piecewise.algorithm.parse::<Algorithm>().ok();
+            for piece in piecewise.pieces.iter_mut() {
+                piece.digest = canonical(algorithm, &piece.digest)?;
+            }
+        }
+        Ok(table)
+    }
+
+    /// id of the [`Key`] of the [`TableV1::keyed`] digests
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// the keyed digest computed by the given algorithm, if any
+    pub fn keyed(&self, algorithm: Algorithm) -> Option<&str> {
+        self.keyed.as_ref()?.get(algorithm.name()).map(String::as_str)
+    }
+
+    fn put(&mut self, slot: Slot, digest: String) {
+        match slot {
+            Slot::Field(algorithm) => self.set(algorithm, digest),
+            Slot::Crc(name) => {
+                self.crcs.insert(name, digest);
+            },
+            Slot::Keyed(algorithm) => {
+                self.keyed
+                    .get_or_insert_with(BTreeMap::new)
+                    .insert(algorithm.to_string(), digest);
+            },
+        }
+    }
 
-        let mut sha3_384 = Sha3_384::new();
-        sha3_384.update(data.as_slice());
-        let sha3_384 = hex::encode(sha3_384.finalize());
+    /// the digest computed by the given algorithm, if it was selected
+    pub fn digest(&self, algorithm: Algorithm) -> Option<&str> {
+        match algorithm {
+            Algorithm::Sha1 => self.sha1.as_deref(),
+            Algorithm::Sha512 => self.sha512.as_deref(),
+            Algorithm::Sha224 => self.sha224.as_deref(),
+            Algorithm::Sha384 => self.sha384.as_deref(),
+            Algorithm::Sha256 => self.sha256.as_deref(),
+            Algorithm::Sha512_224 => self.sha512_224.as_deref(),
+            Algorithm::Sha512_256 => self.sha512_256.as_deref(),
+            Algorithm::Keccak256Full => self.keccak256_full.as_deref(),
+            Algorithm::Keccak256 => self.keccak256.as_deref(),
+            Algorithm::Keccak224 => self.keccak224.as_deref(),
+            Algorithm::Sha3_224 => self.sha3_224.as_deref(),
+            Algorithm::Sha3_256 => self.sha3_256.as_deref(),
+            Algorithm::Sha3_384 => self.sha3_384.as_deref(),
+            Algorithm::Sha3_512 => self.sha3_512.as_deref(),
+            Algorithm::Shake128 => self.shake128.as_deref(),
+            Algorithm::Shake256 => self.shake256.as_deref(),
+            Algorithm::Cshake128 => self.cshake128.as_deref(),
+            Algorithm::Cshake256 => self.cshake256.as_deref(),
+            Algorithm::Blake2b512 => self.blake2b512.as_deref(),
+            Algorithm::Blake2s256 => self.blake2s256.as_deref(),
+            Algorithm::Blake3 => self.blake3.as_deref(),
+            Algorithm::Adler32 => self.adler32.as_deref(),
+            Algorithm::Crc3Gsm => self.crc3_gsm.as_deref(),
+            Algorithm::Crc3Rohc => self.crc3_rohc.as_deref(),
+            Algorithm::Crc4G704 => self.crc4_g704.as_deref(),
+            Algorithm::Crc4Interlaken => self.crc4_interlaken.as_deref(),
+            Algorithm::Crc5EpcC1g2 => self.crc5_epc_c1g2.as_deref(),
+            Algorithm::Crc5G704 => self.crc5_g704.as_deref(),
+            Algorithm::Crc5Usb => self.crc5_usb.as_deref(),
+            Algorithm::Crc6Gsm => self.crc6_gsm.as_deref(),
+            Algorithm::Crc7Mmc => self.crc7_mmc.as_deref(),
+            Algorithm::Crc7Rohc => self.crc7_rohc.as_deref(),
+            Algorithm::Crc7Umts => self.crc7_umts.as_deref(),
+            Algorithm::Crc8Autosar => self.crc8_autosar.as_deref(),
+            Algorithm::Crc8Bluetooth => self.crc8_bluetooth.as_deref(),
+            Algorithm::Crc8Cdma2000 => self.crc8_cdma2000.as_deref(),
+            Algorithm::Crc8Darc => self.crc8_darc.as_deref(),
+            Algorithm::Crc8DvbS2 => self.crc8_dvb_s2.as_deref(),
+            Algorithm::Crc8GsmA => self.crc8_gsm_a.as_deref(),
+            Algorithm::Crc8GsmB => self.crc8_gsm_b.as_deref(),
+            Algorithm::Crc8Hitag => self.crc8_hitag.as_deref(),
+            Algorithm::Crc8I432_1 => self.crc8_i432_1.as_deref(),
+            Algorithm::Crc8ICode => self.crc8_i_code.as_deref(),
+            Algorithm::Crc6Cdma2000a => self.crc6_cdma_2000a.as_deref(),
+            Algorithm::Crc6Cdma2000b => self.crc6_cdma_2000b.as_deref(),
+            Algorithm::Crc6Darc => self.crc6_darc.as_deref(),
+            Algorithm::Crc6G704 => self.crc6_g704.as_deref(),
+            Algorithm::Crc8Lte => self.crc8_lte.as_deref(),
+            Algorithm::Crc8MaximDow => self.crc8_maxim_dow.as_deref(),
+            Algorithm::Crc8MifareMad => self.crc8_mifare_mad.as_deref(),
+            Algorithm::Crc8Nrsc5 => self.crc8_nrsc5.as_deref(),
+            Algorithm::Crc8Opensafety => self.crc8_opensafety.as_deref(),
+            Algorithm::Crc8Rohc => self.crc8_rohc.as_deref(),
+            Algorithm::Crc8SaeJ1850 => self.crc8_sae_j1850.as_deref(),
+            Algorithm::Crc8Smbus => self.crc8_smbus.as_deref(),
+            Algorithm::Crc8Tech3250 => self.crc8_tech3250.as_deref(),
+            Algorithm::Crc8Wcdma => self.crc8_wcdma.as_deref(),
+            Algorithm::Crc10Atm => self.crc10_atm.as_deref(),
+            Algorithm::Crc10Cdma2000 => self.crc10_cdma2000.as_deref(),
+            Algorithm::Crc10Gsm => self.crc10_gsm.as_deref(),
+            Algorithm::Crc11Flexray => self.crc11_flexray.as_deref(),
+            Algorithm::Crc11Umts => self.crc11_umts.as_deref(),
+            Algorithm::Crc12Cdma2000 => self.crc12_cdma2000.as_deref(),
+            Algorithm::Crc12Dect => self.crc12_dect.as_deref(),
+            Algorithm::Crc12Gsm => self.crc12_gsm.as_deref(),
+            Algorithm::Crc12Umts => self.crc12_umts.as_deref(),
+            Algorithm::Crc13Bbc => self.crc13_bbc.as_deref(),
+            Algorithm::Crc14Darc => self.crc14_darc.as_deref(),
+            Algorithm::Crc14Gsm => self.crc14_gsm.as_deref(),
+            Algorithm::Crc15Can => self.crc15_can.as_deref(),
+            Algorithm::Crc15Mpt1327 => self.crc15_mpt1327.as_deref(),
+            Algorithm::Crc16Arc => self.crc16_arc.as_deref(),
+            Algorithm::Crc16Cdma2000 => self.crc16_cdma2000.as_deref(),
+            Algorithm::Crc16Cms => self.crc16_cms.as_deref(),
+            Algorithm::Crc16Dds110 => self.crc16_dds110.as_deref(),
+            Algorithm::Crc16DectR => self.crc16_dect_r.as_deref(),
+            Algorithm::Crc16DectX => self.crc16_dect_x.as_deref(),
+            Algorithm::Crc16Dnp => self.crc16_dnp.as_deref(),
+            Algorithm::Crc16En13757 => self.crc16_en13757.as_deref(),
+            Algorithm::Crc16Genibus => self.crc16_genibus.as_deref(),
+            Algorithm::Crc16Gsm => self.crc16_gsm.as_deref(),
+            Algorithm::Crc16Ibm3740 => self.crc16_ibm3740.as_deref(),
+            Algorithm::Crc16IbmSdlc => self.crc16_ibm_sdlc.as_deref(),
+            Algorithm::Crc16IsoIec14443_3A => self.crc16_iso_iec14443_3_a.as_deref(),
+            Algorithm::Crc16Kermit => self.crc16_kermit.as_deref(),
+            Algorithm::Crc16Lj1200 => self.crc16_lj1200.as_deref(),
+            Algorithm::Crc16M17 => self.crc16_m17.as_deref(),
+            Algorithm::Crc16MaximDow => self.crc16_maxim_dow.as_deref(),
+            Algorithm::Crc16Mcrf4xx => self.crc16_mcrf4xx.as_deref(),
+            Algorithm::Crc16Modbus => self.crc16_modbus.as_deref(),
+            Algorithm::Crc16Nrsc5 => self.crc16_nrsc5.as_deref(),
+            Algorithm::Crc16OpensafetyA => self.crc16_opensafety_a.as_deref(),
+            Algorithm::Crc16OpensafetyB => self.crc16_opensafety_b.as_deref(),
+            Algorithm::Crc16Profibus => self.crc16_profibus.as_deref(),
+            Algorithm::Crc16Riello => self.crc16_riello.as_deref(),
+            Algorithm::Crc16SpiFujitsu => self.crc16_spi_fujitsu.as_deref(),
+            Algorithm::Crc16T10Dif => self.crc16_t10_dif.as_deref(),
+            Algorithm::Crc16Teledisk => self.crc16_teledisk.as_deref(),
+            Algorithm::Crc16Tms37157 => self.crc16_tms37157.as_deref(),
+            Algorithm::Crc16Umts => self.crc16_umts.as_deref(),
+            Algorithm::Crc16Usb => self.crc16_usb.as_deref(),
+            Algorithm::Crc16Xmodem => self.crc16_xmodem.as_deref(),
+            Algorithm::Crc17CanFd => self.crc17_can_fd.as_deref(),
+            Algorithm::Crc21CanFd => self.crc21_can_fd.as_deref(),
+            Algorithm::Crc24Ble => self.crc24_ble.as_deref(),
+            Algorithm::Crc24FlexrayA => self.crc24_flexray_a.as_deref(),
+            Algorithm::Crc24FlexrayB => self.crc24_flexray_b.as_deref(),
+            Algorithm::Crc24Interlaken => self.crc24_interlaken.as_deref(),
+            Algorithm::Crc24LteA => self.crc24_lte_a.as_deref(),
+            Algorithm::Crc24LteB => self.crc24_lte_b.as_deref(),
+            Algorithm::Crc24Openpgp => self.crc24_openpgp.as_deref(),
+            Algorithm::Crc24Os9 => self.crc24_os9.as_deref(),
+            Algorithm::Crc30Cdma => self.crc30_cdma.as_deref(),
+            Algorithm::Crc31Philips => self.crc31_philips.as_deref(),
+            Algorithm::Crc32Aixm => self.crc32_aixm.as_deref(),
+            Algorithm::Crc32Autosar => self.crc32_autosar.as_deref(),
+            Algorithm::Crc32Base91D => self.crc32_base91_d.as_deref(),
+            Algorithm::Crc32Bzip2 => self.crc32_bzip2.as_deref(),
+            Algorithm::Crc32CdRomEdc => self.crc32_cd_rom_edc.as_deref(),
+            Algorithm::Crc32Cksum => self.crc32_cksum.as_deref(),
+            Algorithm::Crc32Iscsi => self.crc32_iscsi.as_deref(),
+            Algorithm::Crc32Isohdlc => self.crc32_isohdlc.as_deref(),
+            Algorithm::Crc32Jamcrc => self.crc32_jamcrc.as_deref(),
+            Algorithm::Crc32Mef => self.crc32_mef.as_deref(),
+            Algorithm::Crc32Mpeg2 => self.crc32_mpeg2.as_deref(),
+            Algorithm::Crc32Xfer => self.crc32_xfer.as_deref(),
+            Algorithm::Crc40Gsm => self.crc40_gsm.as_deref(),
+            Algorithm::Crc64Ecma182 => self.crc64_ecma182.as_deref(),
+            Algorithm::Crc64Goiso => self.crc64_goiso.as_deref(),
+            Algorithm::Crc64Ms => self.crc64_ms.as_deref(),
+            Algorithm::Crc64Nvme => self.crc64_nvme.as_deref(),
+            Algorithm::Crc64Redis => self.crc64_redis.as_deref(),
+            Algorithm::Crc64We => self.crc64_we.as_deref(),
+            Algorithm::Crc64Xz => self.crc64_xz.as_deref(),
+            Algorithm::Crc82Darc => self.crc82_darc.as_deref(),
+            Algorithm::Md5 => self.md5.as_deref(),
+            Algorithm::Ripemd160 => self.ripemd160.as_deref(),
+            Algorithm::Ripemd320 => self.ripemd320.as_deref(),
+            Algorithm::Whirlpool => self.whirlpool.as_deref(),
+            Algorithm::Tiger => self.tiger.as_deref(),
+            Algorithm::Tiger2 => self.tiger2.as_deref(),
+            Algorithm::Streebog256 => self.streebog256.as_deref(),
+            Algorithm::Streebog512 => self.streebog512.as_deref(),
+            Algorithm::Sm3 => self.sm3.as_deref(),
+            Algorithm::Md4 => self.md4.as_deref(),
+            Algorithm::Md2 => self.md2.as_deref(),
+            Algorithm::Sure25 => self.sure25.as_deref(),
+            Algorithm::Xxh32 => self.xxh32.as_deref(),
+            Algorithm::Xxh64 => self.xxh64.as_deref(),
+            Algorithm::Xxh3_64 => self.xxh3_64.as_deref(),
+            Algorithm::Xxh3_128 => self.xxh3_128.as_deref(),
+            Algorithm::Fnv1a64 => self.fnv1a_64.as_deref(),
+            Algorithm::Murmur3_128 => self.murmur3_128.as_deref(),
+            Algorithm::Ssdeep => self.ssdeep.as_deref(),
+            Algorithm::Tlsh => self.tlsh.as_deref(),
+        }
+    }
 
-        let mut sha3_512 = Sha3_512::new();
-        sha3_512.update(data.as_slice());
-        let sha3_512 = hex::encode(sha3_512.finalize());
+    fn set(&mut self, algorithm: Algorithm, digest: String) {
+        let digest = Some(digest);
+        match algorithm {
+            Algorithm::Sha1 => self.sha1 = digest,
+            Algorithm::Sha512 => self.sha512 = digest,
+            Algorithm::Sha224 => self.sha224 = digest,
+            Algorithm::Sha384 => self.sha384 = digest,
+            Algorithm::Sha256 => self.sha256 = digest,
+            Algorithm::Sha512_224 => self.sha512_224 = digest,
+            Algorithm::Sha512_256 => self.sha512_256 = digest,
+            Algorithm::Keccak256Full => self.keccak256_full = digest,
+            Algorithm::Keccak256 => self.keccak256 = digest,
+            Algorithm::Keccak224 => self.keccak224 = digest,
+            Algorithm::Sha3_224 => self.sha3_224 = digest,
+            Algorithm::Sha3_256 => self.sha3_256 = digest,
+            Algorithm::Sha3_384 => self.sha3_384 = digest,
+            Algorithm::Sha3_512 => self.sha3_512 = digest,
+            Algorithm::Shake128 => self.shake128 = digest,
+            Algorithm::Shake256 => self.shake256 = digest,
+            Algorithm::Cshake128 => self.cshake128 = digest,
+            Algorithm::Cshake256 => self.cshake256 = digest,
+            Algorithm::Blake2b512 => self.blake2b512 = digest,
+            Algorithm::Blake2s256 => self.blake2s256 = digest,
+            Algorithm::Blake3 => self.blake3 = digest,
+            Algorithm::Adler32 => self.adler32 = digest,
+            Algorithm::Crc3Gsm => self.crc3_gsm = digest,
+            Algorithm::Crc3Rohc => self.crc3_rohc = digest,
+            Algorithm::Crc4G704 => self.crc4_g704 = digest,
+            Algorithm::Crc4Interlaken => self.crc4_interlaken = digest,
+            Algorithm::Crc5EpcC1g2 => self.crc5_epc_c1g2 = digest,
+            Algorithm::Crc5G704 => self.crc5_g704 = digest,
+            Algorithm::Crc5Usb => self.crc5_usb = digest,
+            Algorithm::Crc6Gsm => self.crc6_gsm = digest,
+            Algorithm::Crc7Mmc => self.crc7_mmc = digest,
+            Algorithm::Crc7Rohc => self.crc7_rohc = digest,
+            Algorithm::Crc7Umts => self.crc7_umts = digest,
+            Algorithm::Crc8Autosar => self.crc8_autosar = digest,
+            Algorithm::Crc8Bluetooth => self.crc8_bluetooth = digest,
+            Algorithm::Crc8Cdma2000 => self.crc8_cdma2000 = digest,
+            Algorithm::Crc8Darc => self.crc8_darc = digest,
+            Algorithm::Crc8DvbS2 => self.crc8_dvb_s2 = digest,
+            Algorithm::Crc8GsmA => self.crc8_gsm_a = digest,
+            Algorithm::Crc8GsmB => self.crc8_gsm_b = digest,
+            Algorithm::Crc8Hitag => self.crc8_hitag = digest,
+            Algorithm::Crc8I432_1 => self.crc8_i432_1 = digest,
+            Algorithm::Crc8ICode => self.crc8_i_code = digest,
+            Algorithm::Crc6Cdma2000a => self.crc6_cdma_2000a = digest,
+            Algorithm::Crc6Cdma2000b => self.crc6_cdma_2000b = digest,
+            Algorithm::Crc6Darc => self.crc6_darc = digest,
+            Algorithm::Crc6G704 => self.crc6_g704 = digest,
+            Algorithm::Crc8Lte => self.crc8_lte = digest,
+            Algorithm::Crc8MaximDow => self.crc8_maxim_dow = digest,
+            Algorithm::Crc8MifareMad => self.crc8_mifare_mad = digest,
+            Algorithm::Crc8Nrsc5 => self.crc8_nrsc5 = digest,
+            Algorithm::Crc8Opensafety => self.crc8_opensafety = digest,
+            Algorithm::Crc8Rohc => self.crc8_rohc = digest,
+            Algorithm::Crc8SaeJ1850 => self.crc8_sae_j1850 = digest,
+            Algorithm::Crc8Smbus => self.crc8_smbus = digest,
+            Algorithm::Crc8Tech3250 => self.crc8_tech3250 = digest,
+            Algorithm::Crc8Wcdma => self.crc8_wcdma = digest,
+            Algorithm::Crc10Atm => self.crc10_atm = digest,
+            Algorithm::Crc10Cdma2000 => self.crc10_cdma2000 = digest,
+            Algorithm::Crc10Gsm => self.crc10_gsm = digest,
+            Algorithm::Crc11Flexray => self.crc11_flexray = digest,
+            Algorithm::Crc11Umts => self.crc11_umts = digest,
+            Algorithm::Crc12Cdma2000 => self.crc12_cdma2000 = digest,
+            Algorithm::Crc12Dect => self.crc12_dect = digest,
+            Algorithm::Crc12Gsm => self.crc12_gsm = digest,
+            Algorithm::Crc12Umts => self.crc12_umts = digest,
+            Algorithm::Crc13Bbc => self.crc13_bbc = digest,
+            Algorithm::Crc14Darc => self.crc14_darc = digest,
+            Algorithm::Crc14Gsm => self.crc14_gsm = digest,
+            Algorithm::Crc15Can => self.crc15_can = digest,
+            Algorithm::Crc15Mpt1327 => self.crc15_mpt1327 = digest,
+            Algorithm::Crc16Arc => self.crc16_arc = digest,
+            Algorithm::Crc16Cdma2000 => self.crc16_cdma2000 = digest,
+            Algorithm::Crc16Cms => self.crc16_cms = digest,
+            Algorithm::Crc16Dds110 => self.crc16_dds110 = digest,
+            Algorithm::Crc16DectR => self.crc16_dect_r = digest,
+            Algorithm::Crc16DectX => self.crc16_dect_x = digest,
+            Algorithm::Crc16Dnp => self.crc16_dnp = digest,
+            Algorithm::Crc16En13757 => self.crc16_en13757 = digest,
+            Algorithm::Crc16Genibus => self.crc16_genibus = digest,
+            Algorithm::Crc16Gsm => self.crc16_gsm = digest,
+            Algorithm::Crc16Ibm3740 => self.crc16_ibm3740 = digest,
+            Algorithm::Crc16IbmSdlc => self.crc16_ibm_sdlc = digest,
+            Algorithm::Crc16IsoIec14443_3A => self.crc16_iso_iec14443_3_a = digest,
+            Algorithm::Crc16Kermit => self.crc16_kermit = digest,
+            Algorithm::Crc16Lj1200 => self.crc16_lj1200 = digest,
+            Algorithm::Crc16M17 => self.crc16_m17 = digest,
+            Algorithm::Crc16MaximDow => self.crc16_maxim_dow = digest,
+            Algorithm::Crc16Mcrf4xx => self.crc16_mcrf4xx = digest,
+            Algorithm::Crc16Modbus => self.crc16_modbus = digest,
+            Algorithm::Crc16Nrsc5 => self.crc16_nrsc5 = digest,
+            Algorithm::Crc16OpensafetyA => self.crc16_opensafety_a = digest,
+            Algorithm::Crc16OpensafetyB => self.crc16_opensafety_b = digest,
+            Algorithm::Crc16Profibus => self.crc16_profibus = digest,
+            Algorithm::Crc16Riello => self.crc16_riello = digest,
+            Algorithm::Crc16SpiFujitsu => self.crc16_spi_fujitsu = digest,
+            Algorithm::Crc16T10Dif => self.crc16_t10_dif = digest,
+            Algorithm::Crc16Teledisk => self.crc16_teledisk = digest,
+            Algorithm::Crc16Tms37157 => self.crc16_tms37157 = digest,
+            Algorithm::Crc16Umts => self.crc16_umts = digest,
+            Algorithm::Crc16Usb => self.crc16_usb = digest,
+            Algorithm::Crc16Xmodem => self.crc16_xmodem = digest,
+            Algorithm::Crc17CanFd => self.crc17_can_fd = digest,
+            Algorithm::Crc21CanFd => self.crc21_can_fd = digest,
+            Algorithm::Crc24Ble => self.crc24_ble = digest,
+            Algorithm::Crc24FlexrayA => self.crc24_flexray_a = digest,
+            Algorithm::Crc24FlexrayB => self.crc24_flexray_b = digest,
+            Algorithm::Crc24Interlaken => self.crc24_interlaken = digest,
+            Algorithm::Crc24LteA => self.crc24_lte_a = digest,
+            Algorithm::Crc24LteB => self.crc24_lte_b = digest,
+            Algorithm::Crc24Openpgp => self.crc24_openpgp = digest,
+            Algorithm::Crc24Os9 => self.crc24_os9 = digest,
+            Algorithm::Crc30Cdma => self.crc30_cdma = digest,
+            Algorithm::Crc31Philips => self.crc31_philips = digest,
+            Algorithm::Crc32Aixm => self.crc32_aixm = digest,
+            Algorithm::Crc32Autosar => self.crc32_autosar = digest,
+            Algorithm::Crc32Base91D => self.crc32_base91_d = digest,
+            Algorithm::Crc32Bzip2 => self.crc32_bzip2 = digest,
+            Algorithm::Crc32CdRomEdc => self.crc32_cd_rom_edc = digest,
+            Algorithm::Crc32Cksum => self.crc32_cksum = digest,
+            Algorithm::Crc32Iscsi => self.crc32_iscsi = digest,
+            Algorithm::Crc32Isohdlc => self.crc32_isohdlc = digest,
+            Algorithm::Crc32Jamcrc => self.crc32_jamcrc = digest,
+            Algorithm::Crc32Mef => self.crc32_mef = digest,
+            Algorithm::Crc32Mpeg2 => self.crc32_mpeg2 = digest,
+            Algorithm::Crc32Xfer => self.crc32_xfer = digest,
+            Algorithm::Crc40Gsm => self.crc40_gsm = digest,
+            Algorithm::Crc64Ecma182 => self.crc64_ecma182 = digest,
+            Algorithm::Crc64Goiso => self.crc64_goiso = digest,
+            Algorithm::Crc64Ms => self.crc64_ms = digest,
+            Algorithm::Crc64Nvme => self.crc64_nvme = digest,
+            Algorithm::Crc64Redis => self.crc64_redis = digest,
+            Algorithm::Crc64We => self.crc64_we = digest,
+            Algorithm::Crc64Xz => self.crc64_xz = digest,
+            Algorithm::Crc82Darc => self.crc82_darc = digest,
+            Algorithm::Md5 => self.md5 = digest,
+            Algorithm::Ripemd160 => self.ripemd160 = digest,
+            Algorithm::Ripemd320 => self.ripemd320 = digest,
+            Algorithm::Whirlpool => self.whirlpool = digest,
+            Algorithm::Tiger => self.tiger = digest,
+            Algorithm::Tiger2 => self.tiger2 = digest,
+            Algorithm::Streebog256 => self.streebog256 = digest,
+            Algorithm::Streebog512 => self.streebog512 = digest,
+            Algorithm::Sm3 => self.sm3 = digest,
+            Algorithm::Md4 => self.md4 = digest,
+            Algorithm::Md2 => self.md2 = digest,
+            Algorithm::Sure25 => self.sure25 = digest,
+            Algorithm::Xxh32 => self.xxh32 = digest,
+            Algorithm::Xxh64 => self.xxh64 = digest,
+            Algorithm::Xxh3_64 => self.xxh3_64 = digest,
+            Algorithm::Xxh3_128 => self.xxh3_128 = digest,
+            Algorithm::Fnv1a64 => self.fnv1a_64 = digest,
+            Algorithm::Murmur3_128 => self.murmur3_128 = digest,
+            Algorithm::Ssdeep => self.ssdeep = digest,
+            Algorithm::Tlsh => self.tlsh = digest,
+        }
+    }
+}
 
-        let mut keccak224 = Keccak224::new();
-        keccak224.update(data.as_slice());
-        let keccak224 = hex::encode(keccak224.finalize());
+/// fills `chunk` from `reader` retrying short reads so that chunk
+/// boundaries only depend on the data, never on the reader.
+fn read_chunk(reader: &mut impl Read, chunk: &mut [u8]) -> Result<usize, Error> {
+    let mut length = 0;
+    while length < chunk.len() {
+        match reader.read(&mut chunk[length..]) {
+            Ok(0) => break,
+            Ok(count) => length += count,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(Error::IOError(e)),
+        }
+    }
+    Ok(length)
+}
 
-        let mut keccak256 = Keccak256::new();
-        keccak256.update(data.as_slice());
-        let keccak256 = hex::encode(keccak256.finalize());
+static CRC3_GSM: Crc<u8> = Crc::<u8>::new(&CRC_3_GSM);
+static CRC3_ROHC: Crc<u8> = Crc::<u8>::new(&CRC_3_ROHC);
+static CRC4_G704: Crc<u8> = Crc::<u8>::new(&CRC_4_G_704);
+static CRC4_INTERLAKEN: Crc<u8> = Crc::<u8>::new(&CRC_4_INTERLAKEN);
+static CRC5_EPC_C1G2: Crc<u8> = Crc::<u8>::new(&CRC_5_EPC_C1G2);
+static CRC5_G704: Crc<u8> = Crc::<u8>::new(&CRC_5_G_704);
+static CRC5_USB: Crc<u8> = Crc::<u8>::new(&CRC_5_USB);
+static CRC6_GSM: Crc<u8> = Crc::<u8>::new(&CRC_6_GSM);
+static CRC7_MMC: Crc<u8> = Crc::<u8>::new(&CRC_7_MMC);
+static CRC7_ROHC: Crc<u8> = Crc::<u8>::new(&CRC_7_ROHC);
+static CRC7_UMTS: Crc<u8> = Crc::<u8>::new(&CRC_7_UMTS);
+static CRC8_AUTOSAR: Crc<u8> = Crc::<u8>::new(&CRC_8_AUTOSAR);
+static CRC8_BLUETOOTH: Crc<u8> = Crc::<u8>::new(&CRC_8_BLUETOOTH);
+static CRC8_CDMA2000: Crc<u8> = Crc::<u8>::new(&CRC_8_CDMA2000);
+static CRC8_DARC: Crc<u8> = Crc::<u8>::new(&CRC_8_DARC);
+static CRC8_DVB_S2: Crc<u8> = Crc::<u8>::new(&CRC_8_DVB_S2);
+static CRC8_GSM_A: Crc<u8> = Crc::<u8>::new(&CRC_8_GSM_A);
+static CRC8_GSM_B: Crc<u8> = Crc::<u8>::new(&CRC_8_GSM_B);
+static CRC8_HITAG: Crc<u8> = Crc::<u8>::new(&CRC_8_HITAG);
+static CRC8_I432_1: Crc<u8> = Crc::<u8>::new(&CRC_8_I_432_1);
+static CRC8_I_CODE: Crc<u8> = Crc::<u8>::new(&CRC_8_I_CODE);
+static CRC6_CDMA_2000A: Crc<u8> = Crc::<u8>::new(&CRC_6_CDMA2000_A);
+static CRC6_CDMA_2000B: Crc<u8> = Crc::<u8>::new(&CRC_6_CDMA2000_B);
+static CRC6_DARC: Crc<u8> = Crc::<u8>::new(&CRC_6_DARC);
+static CRC6_G704: Crc<u8> = Crc::<u8>::new(&CRC_6_G_704);
+static CRC8_LTE: Crc<u8> = Crc::<u8>::new(&CRC_8_LTE);
+static CRC8_MAXIM_DOW: Crc<u8> = Crc::<u8>::new(&CRC_8_MAXIM_DOW);
+static CRC8_MIFARE_MAD: Crc<u8> = Crc::<u8>::new(&CRC_8_MIFARE_MAD);
+static CRC8_NRSC5: Crc<u8> = Crc::<u8>::new(&CRC_8_NRSC_5);
+static CRC8_OPENSAFETY: Crc<u8> = Crc::<u8>::new(&CRC_8_OPENSAFETY);
+static CRC8_ROHC: Crc<u8> = Crc::<u8>::new(&CRC_8_ROHC);
+static CRC8_SAE_J1850: Crc<u8> = Crc::<u8>::new(&CRC_8_SAE_J1850);
+static CRC8_SMBUS: Crc<u8> = Crc::<u8>::new(&CRC_8_SMBUS);
+static CRC8_TECH3250: Crc<u8> = Crc::<u8>::new(&CRC_8_TECH_3250);
+static CRC8_WCDMA: Crc<u8> = Crc::<u8>::new(&CRC_8_WCDMA);
+static CRC10_ATM: Crc<u16> = Crc::<u16>::new(&CRC_10_ATM);
+static CRC10_CDMA2000: Crc<u16> = Crc::<u16>::new(&CRC_10_CDMA2000);
+static CRC10_GSM: Crc<u16> = Crc::<u16>::new(&CRC_10_GSM);
+static CRC11_FLEXRAY: Crc<u16> = Crc::<u16>::new(&CRC_11_FLEXRAY);
+static CRC11_UMTS: Crc<u16> = Crc::<u16>::new(&CRC_11_UMTS);
+static CRC12_CDMA2000: Crc<u16> = Crc::<u16>::new(&CRC_12_CDMA2000);
+static CRC12_DECT: Crc<u16> = Crc::<u16>::new(&CRC_12_DECT);
+static CRC12_GSM: Crc<u16> = Crc::<u16>::new(&CRC_12_GSM);
+static CRC12_UMTS: Crc<u16> = Crc::<u16>::new(&CRC_12_UMTS);
+static CRC13_BBC: Crc<u16> = Crc::<u16>::new(&CRC_13_BBC);
+static CRC14_DARC: Crc<u16> = Crc::<u16>::new(&CRC_14_DARC);
+static CRC14_GSM: Crc<u16> = Crc::<u16>::new(&CRC_14_GSM);
+static CRC15_CAN: Crc<u16> = Crc::<u16>::new(&CRC_15_CAN);
+static CRC15_MPT1327: Crc<u16> = Crc::<u16>::new(&CRC_15_MPT1327);
+static CRC16_ARC: Crc<u16> = Crc::<u16>::new(&CRC_16_ARC);
+static CRC16_CDMA2000: Crc<u16> = Crc::<u16>::new(&CRC_16_CDMA2000);
+static CRC16_CMS: Crc<u16> = Crc::<u16>::new(&CRC_16_CMS);
+static CRC16_DDS110: Crc<u16> = Crc::<u16>::new(&CRC_16_DDS_110);
+static CRC16_DECT_R: Crc<u16> = Crc::<u16>::new(&CRC_16_DECT_R);
+static CRC16_DECT_X: Crc<u16> = Crc::<u16>::new(&CRC_16_DECT_X);
+static CRC16_DNP: Crc<u16> = Crc::<u16>::new(&CRC_16_DNP);
+static CRC16_EN13757: Crc<u16> = Crc::<u16>::new(&CRC_16_EN_13757);
+static CRC16_GENIBUS: Crc<u16> = Crc::<u16>::new(&CRC_16_GENIBUS);
+static CRC16_GSM: Crc<u16> = Crc::<u16>::new(&CRC_16_GSM);
+static CRC16_IBM3740: Crc<u16> = Crc::<u16>::new(&CRC_16_IBM_3740);
+static CRC16_IBM_SDLC: Crc<u16> = Crc::<u16>::new(&CRC_16_IBM_SDLC);
+static CRC16_ISO_IEC14443_3_A: Crc<u16> = Crc::<u16>::new(&CRC_16_ISO_IEC_14443_3_A);
+static CRC16_KERMIT: Crc<u16> = Crc::<u16>::new(&CRC_16_KERMIT);
+static CRC16_LJ1200: Crc<u16> = Crc::<u16>::new(&CRC_16_LJ1200);
+static CRC16_M17: Crc<u16> = Crc::<u16>::new(&CRC_16_M17);
+static CRC16_MAXIM_DOW: Crc<u16> = Crc::<u16>::new(&CRC_16_MAXIM_DOW);
+static CRC16_MCRF4XX: Crc<u16> = Crc::<u16>::new(&CRC_16_MCRF4XX);
+static CRC16_MODBUS: Crc<u16> = Crc::<u16>::new(&CRC_16_MODBUS);
+static CRC16_NRSC5: Crc<u16> = Crc::<u16>::new(&CRC_16_NRSC_5);
+static CRC16_TELEDISK: Crc<u16> = Crc::<u16>::new(&CRC_16_TELEDISK);
+static CRC16_TMS37157: Crc<u16> = Crc::<u16>::new(&CRC_16_TMS37157);
+static CRC16_UMTS: Crc<u16> = Crc::<u16>::new(&CRC_16_UMTS);
+static CRC16_OPENSAFETY_A: Crc<u16> = Crc::<u16>::new(&CRC_16_OPENSAFETY_A);
+static CRC16_OPENSAFETY_B: Crc<u16> = Crc::<u16>::new(&CRC_16_OPENSAFETY_B);
+static CRC16_PROFIBUS: Crc<u16> = Crc::<u16>::new(&CRC_16_PROFIBUS);
+static CRC16_RIELLO: Crc<u16> = Crc::<u16>::new(&CRC_16_RIELLO);
+static CRC16_SPI_FUJITSU: Crc<u16> = Crc::<u16>::new(&CRC_16_SPI_FUJITSU);
+static CRC16_T10_DIF: Crc<u16> = Crc::<u16>::new(&CRC_16_T10_DIF);
+static CRC16_USB: Crc<u16> = Crc::<u16>::new(&CRC_16_USB);
+static CRC16_XMODEM: Crc<u16> = Crc::<u16>::new(&CRC_16_XMODEM);
+static CRC17_CAN_FD: Crc<u32> = Crc::<u32>::new(&CRC_17_CAN_FD);
+static CRC21_CAN_FD: Crc<u32> = Crc::<u32>::new(&CRC_21_CAN_FD);
+static CRC24_BLE: Crc<u32> = Crc::<u32>::new(&CRC_24_BLE);
+static CRC24_FLEXRAY_A: Crc<u32> = Crc::<u32>::new(&CRC_24_FLEXRAY_A);
+static CRC24_FLEXRAY_B: Crc<u32> = Crc::<u32>::new(&CRC_24_FLEXRAY_B);
+static CRC24_INTERLAKEN: Crc<u32> = Crc::<u32>::new(&CRC_24_INTERLAKEN);
+static CRC24_LTE_A: Crc<u32> = Crc::<u32>::new(&CRC_24_LTE_A);
+static CRC24_LTE_B: Crc<u32> = Crc::<u32>::new(&CRC_24_LTE_B);
+static CRC24_OPENPGP: Crc<u32> = Crc::<u32>::new(&CRC_24_OPENPGP);
+static CRC24_OS9: Crc<u32> = Crc::<u32>::new(&CRC_24_OS_9);
+static CRC30_CDMA: Crc<u32> = Crc::<u32>::new(&CRC_30_CDMA);
+static CRC31_PHILIPS: Crc<u32> = Crc::<u32>::new(&CRC_31_PHILIPS);
+static CRC32_AIXM: Crc<u32> = Crc::<u32>::new(&CRC_32_AIXM);
+static CRC32_AUTOSAR: Crc<u32> = Crc::<u32>::new(&CRC_32_AUTOSAR);
+static CRC32_BASE91_D: Crc<u32> = Crc::<u32>::new(&CRC_32_BASE91_D);
+static CRC32_BZIP2: Crc<u32> = Crc::<u32>::new(&CRC_32_BZIP2);
+static CRC32_CD_ROM_EDC: Crc<u32> = Crc::<u32>::new(&CRC_32_CD_ROM_EDC);
+static CRC32_CKSUM: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
+static CRC32_ISCSI: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+static CRC32_ISOHDLC: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+static CRC32_JAMCRC: Crc<u32> = Crc::<u32>::new(&CRC_32_JAMCRC);
+static CRC32_MEF: Crc<u32> = Crc::<u32>::new(&CRC_32_MEF);
+static CRC32_MPEG2: Crc<u32> = Crc::<u32>::new(&CRC_32_MPEG_2);
+static CRC32_XFER: Crc<u32> = Crc::<u32>::new(&CRC_32_XFER);
+static CRC40_GSM: Crc<u64> = Crc::<u64>::new(&CRC_40_GSM);
+static CRC64_ECMA182: Crc<u64> = Crc::<u64>::new(&CRC_64_ECMA_182);
+static CRC64_GOISO: Crc<u64> = Crc::<u64>::new(&CRC_64_GO_ISO);
+static CRC64_MS: Crc<u64> = Crc::<u64>::new(&CRC_64_MS);
+static CRC64_NVME: Crc<u64> = Crc::<u64>::new(&CRC_64_NVME);
+static CRC64_REDIS: Crc<u64> = Crc::<u64>::new(&CRC_64_REDIS);
+static CRC64_WE: Crc<u64> = Crc::<u64>::new(&CRC_64_WE);
+static CRC64_XZ: Crc<u64> = Crc::<u64>::new(&CRC_64_XZ);
+static CRC82_DARC: Crc<u128> = Crc::<u128>::new(&CRC_82_DARC);
 
-        let mut keccak256_full = Keccak256Full::new();
-        keccak256_full.update(data.as_slice());
-        let keccak256_full = hex::encode(keccak256_full.finalize());
+/// every digest [`TableV1`] knows how to compute, each one named
+/// after the field it fills in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Algorithm {
+    Sha1,
+    Sha512,
+    Sha224,
+    Sha384,
+    Sha256,
+    Sha512_224,
+    Sha512_256,
+    Keccak256Full,
+    Keccak256,
+    Keccak224,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Shake128,
+    Shake256,
+    Cshake128,
+    Cshake256,
+    Blake2b512,
+    Blake2s256,
+    Blake3,
+    Adler32,
+    Crc3Gsm,
+    Crc3Rohc,
+    Crc4G704,
+    Crc4Interlaken,
+    Crc5EpcC1g2,
+    Crc5G704,
+    Crc5Usb,
+    Crc6Gsm,
+    Crc7Mmc,
+    Crc7Rohc,
+    Crc7Umts,
+    Crc8Autosar,
+    Crc8Bluetooth,
+    Crc8Cdma2000,
+    Crc8Darc,
+    Crc8DvbS2,
+    Crc8GsmA,
+    Crc8GsmB,
+    Crc8Hitag,
+    Crc8I432_1,
+    Crc8ICode,
+    Crc6Cdma2000a,
+    Crc6Cdma2000b,
+    Crc6Darc,
+    Crc6G704,
+    Crc8Lte,
+    Crc8MaximDow,
+    Crc8MifareMad,
+    Crc8Nrsc5,
+    Crc8Opensafety,
+    Crc8Rohc,
+    Crc8SaeJ1850,
+    Crc8Smbus,
+    Crc8Tech3250,
+    Crc8Wcdma,
+    Crc10Atm,
+    Crc10Cdma2000,
+    Crc10Gsm,
+    Crc11Flexray,
+    Crc11Umts,
+    Crc12Cdma2000,
+    Crc12Dect,
+    Crc12Gsm,
+    Crc12Umts,
+    Crc13Bbc,
+    Crc14Darc,
+    Crc14Gsm,
+    Crc15Can,
+    Crc15Mpt1327,
+    Crc16Arc,
+    Crc16Cdma2000,
+    Crc16Cms,
+    Crc16Dds110,
+    Crc16DectR,
+    Crc16DectX,
+    Crc16Dnp,
+    Crc16En13757,
+    Crc16Genibus,
+    Crc16Gsm,
+    Crc16Ibm3740,
+    Crc16IbmSdlc,
+    Crc16IsoIec14443_3A,
+    Crc16Kermit,
+    Crc16Lj1200,
+    Crc16M17,
+    Crc16MaximDow,
+    Crc16Mcrf4xx,
+    Crc16Modbus,
+    Crc16Nrsc5,
+    Crc16OpensafetyA,
+    Crc16OpensafetyB,
+    Crc16Profibus,
+    Crc16Riello,
+    Crc16SpiFujitsu,
+    Crc16T10Dif,
+    Crc16Teledisk,
+    Crc16Tms37157,
+    Crc16Umts,
+    Crc16Usb,
+    Crc16Xmodem,
+    Crc17CanFd,
+    Crc21CanFd,
+    Crc24Ble,
+    Crc24FlexrayA,
+    Crc24FlexrayB,
+    Crc24Interlaken,
+    Crc24LteA,
+    Crc24LteB,
+    Crc24Openpgp,
+    Crc24Os9,
+    Crc30Cdma,
+    Crc31Philips,
+    Crc32Aixm,
+    Crc32Autosar,
+    Crc32Base91D,
+    Crc32Bzip2,
+    Crc32CdRomEdc,
+    Crc32Cksum,
+    Crc32Iscsi,
+    Crc32Isohdlc,
+    Crc32Jamcrc,
+    Crc32Mef,
+    Crc32Mpeg2,
+    Crc32Xfer,
+    Crc40Gsm,
+    Crc64Ecma182,
+    Crc64Goiso,
+    Crc64Ms,
+    Crc64Nvme,
+    Crc64Redis,
+    Crc64We,
+    Crc64Xz,
+    Crc82Darc,
+    Md5,
+    Ripemd160,
+    Ripemd320,
+    Whirlpool,
+    Tiger,
+    Tiger2,
+    Streebog256,
+    Streebog512,
+    Sm3,
+    Md4,
+    Md2,
+    Sure25,
+    Xxh32,
+    Xxh64,
+    Xxh3_64,
+    Xxh3_128,
+    Fnv1a64,
+    Murmur3_128,
+    Ssdeep,
+    Tlsh,
+}
 
-        let mut sha2 = Sha512_224::new();
-        sha2.update(data.as_slice());
-        let sha512_224 = hex::encode(sha2.finalize());
+impl Algorithm {
+    pub const ALL: &'static [Algorithm] = &[
+        Algorithm::Sha1,
+        Algorithm::Sha512,
+        Algorithm::Sha224,
+        Algorithm::Sha384,
+        Algorithm::Sha256,
+        Algorithm::Sha512_224,
+        Algorithm::Sha512_256,
+        Algorithm::Keccak256Full,
+        Algorithm::Keccak256,
+        Algorithm::Keccak224,
+        Algorithm::Sha3_224,
+        Algorithm::Sha3_256,
+        Algorithm::Sha3_384,
+        Algorithm::Sha3_512,
+        Algorithm::Blake2b512,
+        Algorithm::Blake2s256,
+        Algorithm::Blake3,
+        Algorithm::Adler32,
+        Algorithm::Crc3Gsm,
+        Algorithm::Crc4G704,
+        Algorithm::Crc5G704,
+        Algorithm::Crc6Gsm,
+        Algorithm::Crc6Cdma2000a,
+        Algorithm::Crc6Cdma2000b,
+        Algorithm::Crc8Lte,
+        Algorithm::Crc11Flexray,
+        Algorithm::Crc16OpensafetyA,
+        Algorithm::Crc16OpensafetyB,
+        Algorithm::Crc16Profibus,
+        Algorithm::Crc16Teledisk,
+        Algorithm::Crc16Usb,
+        Algorithm::Crc16Xmodem,
+        Algorithm::Crc24Ble,
+        Algorithm::Crc24Openpgp,
+        Algorithm::Crc32Bzip2,
+        Algorithm::Crc32Iscsi,
+        Algorithm::Crc32Isohdlc,
+        Algorithm::Crc32Jamcrc,
+        Algorithm::Crc32Mpeg2,
+        Algorithm::Crc40Gsm,
+        Algorithm::Crc64Ecma182,
+        Algorithm::Crc64Goiso,
+        Algorithm::Crc64Ms,
+        Algorithm::Crc64Redis,
+        Algorithm::Crc64We,
+        Algorithm::Crc64Xz,
+        Algorithm::Crc82Darc,
+        Algorithm::Md5,
+        Algorithm::Ripemd160,
+        Algorithm::Ripemd320,
+        Algorithm::Sure25,
+    ];
+    pub const FAST: &'static [Algorithm] = &[
+        Algorithm::Adler32,
+        Algorithm::Crc32Isohdlc,
+        Algorithm::Crc32Iscsi,
+        Algorithm::Crc64Xz,
+        Algorithm::Md5,
+        Algorithm::Sha1,
+    ];
+    pub const FORENSIC: &'static [Algorithm] = &[
+        Algorithm::Md5,
+        Algorithm::Sha1,
+        Algorithm::Sha256,
+        Algorithm::Sha512,
+        Algorithm::Sha3_256,
+    ];
 
-        let mut sha2 = Sha512_256::new();
-        sha2.update(data.as_slice());
-        let sha512_256 = hex::encode(sha2.finalize());
+    /// similarity digests compared by `carbon14 similar`, left out of
+    /// [`Algorithm::ALL`]
+    pub const FUZZY: &'static [Algorithm] = &[Algorithm::Ssdeep, Algorithm::Tlsh];
+    /// seedable non-cryptographic digests, left out of [`Algorithm::ALL`]
+    pub const NONCRYPTO: &'static [Algorithm] = &[
+        Algorithm::Xxh32,
+        Algorithm::Xxh64,
+        Algorithm::Xxh3_64,
+        Algorithm::Xxh3_128,
+        Algorithm::Fnv1a64,
+        Algorithm::Murmur3_128,
+    ];
+    /// digests found in older case files and reports, e.g.: Tiger as in
+    /// `hashdeep` or GOST Streebog, left out of [`Algorithm::ALL`]
+    pub const LEGACY: &'static [Algorithm] = &[
+        Algorithm::Whirlpool,
+        Algorithm::Tiger,
+        Algorithm::Tiger2,
+        Algorithm::Streebog256,
+        Algorithm::Streebog512,
+        Algorithm::Sm3,
+        Algorithm::Md4,
+        Algorithm::Md2,
+    ];
+    /// extendable-output functions of `--xof-length` bytes, left out of
+    /// [`Algorithm::ALL`]
+    pub const XOF: &'static [Algorithm] = &[
+        Algorithm::Shake128,
+        Algorithm::Shake256,
+        Algorithm::Cshake128,
+        Algorithm::Cshake256,
+    ];
+    /// algorithms which [`Digester::with_key`] computes as HMAC, except
+    /// for BLAKE3 which is keyed natively
+    pub const KEYED: &'static [Algorithm] = &[
+        Algorithm::Sha224,
+        Algorithm::Sha256,
+        Algorithm::Sha384,
+        Algorithm::Sha512,
+        Algorithm::Sha512_224,
+        Algorithm::Sha512_256,
+        Algorithm::Sha3_224,
+        Algorithm::Sha3_256,
+        Algorithm::Sha3_384,
+        Algorithm::Sha3_512,
+        Algorithm::Ripemd160,
+        Algorithm::Ripemd320,
+        Algorithm::Blake3,
+    ];
+    /// every CRC of the catalogue of the `crc` crate, of which
+    /// [`Algorithm::ALL`] only computes those of older versions
+    pub const CRC: &'static [Algorithm] = &[
+        Algorithm::Crc3Gsm,
+        Algorithm::Crc3Rohc,
+        Algorithm::Crc4G704,
+        Algorithm::Crc4Interlaken,
+        Algorithm::Crc5EpcC1g2,
+        Algorithm::Crc5G704,
+        Algorithm::Crc5Usb,
+        Algorithm::Crc6Cdma2000a,
+        Algorithm::Crc6Cdma2000b,
+        Algorithm::Crc6Darc,
+        Algorithm::Crc6G704,
+        Algorithm::Crc6Gsm,
+        Algorithm::Crc7Mmc,
+        Algorithm::Crc7Rohc,
+        Algorithm::Crc7Umts,
+        Algorithm::Crc8Autosar,
+        Algorithm::Crc8Bluetooth,
+        Algorithm::Crc8Cdma2000,
+        Algorithm::Crc8Darc,
+        Algorithm::Crc8DvbS2,
+        Algorithm::Crc8GsmA,
+        Algorithm::Crc8GsmB,
+        Algorithm::Crc8Hitag,
+        Algorithm::Crc8I432_1,
+        Algorithm::Crc8ICode,
+        Algorithm::Crc8Lte,
+        Algorithm::Crc8MaximDow,
+        Algorithm::Crc8MifareMad,
+        Algorithm::Crc8Nrsc5,
+        Algorithm::Crc8Opensafety,
+        Algorithm::Crc8Rohc,
+        Algorithm::Crc8SaeJ1850,
+        Algorithm::Crc8Smbus,
+        Algorithm::Crc8Tech3250,
+        Algorithm::Crc8Wcdma,
+        Algorithm::Crc10Atm,
+        Algorithm::Crc10Cdma2000,
+        Algorithm::Crc10Gsm,
+        Algorithm::Crc11Flexray,
+        Algorithm::Crc11Umts,
+        Algorithm::Crc12Cdma2000,
+        Algorithm::Crc12Dect,
+        Algorithm::Crc12Gsm,
+        Algorithm::Crc12Umts,
+        Algorithm::Crc13Bbc,
+        Algorithm::Crc14Darc,
+        Algorithm::Crc14Gsm,
+        Algorithm::Crc15Can,
+        Algorithm::Crc15Mpt1327,
+        Algorithm::Crc16Arc,
+        Algorithm::Crc16Cdma2000,
+        Algorithm::Crc16Cms,
+        Algorithm::Crc16Dds110,
+        Algorithm::Crc16DectR,
+        Algorithm::Crc16DectX,
+        Algorithm::Crc16Dnp,
+        Algorithm::Crc16En13757,
+        Algorithm::Crc16Genibus,
+        Algorithm::Crc16Gsm,
+        Algorithm::Crc16Ibm3740,
+        Algorithm::Crc16IbmSdlc,
+        Algorithm::Crc16IsoIec14443_3A,
+        Algorithm::Crc16Kermit,
+        Algorithm::Crc16Lj1200,
+        Algorithm::Crc16M17,
+        Algorithm::Crc16MaximDow,
+        Algorithm::Crc16Mcrf4xx,
+        Algorithm::Crc16Modbus,
+        Algorithm::Crc16Nrsc5,
+        Algorithm::Crc16OpensafetyA,
+        Algorithm::Crc16OpensafetyB,
+        Algorithm::Crc16Profibus,
+        Algorithm::Crc16Riello,
+        Algorithm::Crc16SpiFujitsu,
+        Algorithm::Crc16T10Dif,
+        Algorithm::Crc16Teledisk,
+        Algorithm::Crc16Tms37157,
+        Algorithm::Crc16Umts,
+        Algorithm::Crc16Usb,
+        Algorithm::Crc16Xmodem,
+        Algorithm::Crc17CanFd,
+        Algorithm::Crc21CanFd,
+        Algorithm::Crc24Ble,
+        Algorithm::Crc24FlexrayA,
+        Algorithm::Crc24FlexrayB,
+        Algorithm::Crc24Interlaken,
+        Algorithm::Crc24LteA,
+        Algorithm::Crc24LteB,
+        Algorithm::Crc24Openpgp,
+        Algorithm::Crc24Os9,
+        Algorithm::Crc30Cdma,
+        Algorithm::Crc31Philips,
+        Algorithm::Crc32Aixm,
+        Algorithm::Crc32Autosar,
+        Algorithm::Crc32Base91D,
+        Algorithm::Crc32Bzip2,
+        Algorithm::Crc32CdRomEdc,
+        Algorithm::Crc32Cksum,
+        Algorithm::Crc32Iscsi,
+        Algorithm::Crc32Isohdlc,
+        Algorithm::Crc32Jamcrc,
+        Algorithm::Crc32Mef,
+        Algorithm::Crc32Mpeg2,
+        Algorithm::Crc32Xfer,
+        Algorithm::Crc40Gsm,
+        Algorithm::Crc64Ecma182,
+        Algorithm::Crc64Goiso,
+        Algorithm::Crc64Ms,
+        Algorithm::Crc64Nvme,
+        Algorithm::Crc64Redis,
+        Algorithm::Crc64We,
+        Algorithm::Crc64Xz,
+        Algorithm::Crc82Darc,
+    ];
 
-        let mut sha2 = Sha224::new();
-        sha2.update(data.as_slice());
-        let sha224 = hex::encode(sha2.finalize());
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Sha1 => "sha1",
+            Algorithm::Sha512 => "sha512",
+            Algorithm::Sha224 => "sha224",
+            Algorithm::Sha384 => "sha384",
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha512_224 => "sha512_224",
+            Algorithm::Sha512_256 => "sha512_256",
+            Algorithm::Keccak256Full => "keccak256_full",
+            Algorithm::Keccak256 => "keccak256",
+            Algorithm::Keccak224 => "keccak224",
+            Algorithm::Sha3_224 => "sha3_224",
+            Algorithm::Sha3_256 => "sha3_256",
+            Algorithm::Sha3_384 => "sha3_384",
+            Algorithm::Sha3_512 => "sha3_512",
+            Algorithm::Shake128 => "shake128",
+            Algorithm::Shake256 => "shake256",
+            Algorithm::Cshake128 => "cshake128",
+            Algorithm::Cshake256 => "cshake256",
+            Algorithm::Blake2b512 => "blake2b512",
+            Algorithm::Blake2s256 => "blake2s256",
+            Algorithm::Blake3 => "blake3",
+            Algorithm::Adler32 => "adler32",
+            Algorithm::Crc3Gsm => "crc3_gsm",
+            Algorithm::Crc3Rohc => "crc3_rohc",
+            Algorithm::Crc4G704 => "crc4_g704",
+            Algorithm::Crc4Interlaken => "crc4_interlaken",
+            Algorithm::Crc5EpcC1g2 => "crc5_epc_c1g2",
+            Algorithm::Crc5G704 => "crc5_g704",
+            Algorithm::Crc5Usb => "crc5_usb",
+            Algorithm::Crc6Gsm => "crc6_gsm",
+            Algorithm::Crc7Mmc => "crc7_mmc",
+            Algorithm::Crc7Rohc => "crc7_rohc",
+            Algorithm::Crc7Umts => "crc7_umts",
+            Algorithm::Crc8Autosar => "crc8_autosar",
+            Algorithm::Crc8Bluetooth => "crc8_bluetooth",
+            Algorithm::Crc8Cdma2000 => "crc8_cdma2000",
+            Algorithm::Crc8Darc => "crc8_darc",
+            Algorithm::Crc8DvbS2 => "crc8_dvb_s2",
+            Algorithm::Crc8GsmA => "crc8_gsm_a",
+            Algorithm::Crc8GsmB => "crc8_gsm_b",
+            Algorithm::Crc8Hitag => "crc8_hitag",
+            Algorithm::Crc8I432_1 => "crc8_i432_1",
+            Algorithm::Crc8ICode => "crc8_i_code",
+            Algorithm::Crc6Cdma2000a => "crc6_cdma_2000a",
+            Algorithm::Crc6Cdma2000b => "crc6_cdma_2000b",
+            Algorithm::Crc6Darc => "crc6_darc",
+            Algorithm::Crc6G704 => "crc6_g704",
+            Algorithm::Crc8Lte => "crc8_lte",
+            Algorithm::Crc8MaximDow => "crc8_maxim_dow",
+            Algorithm::Crc8MifareMad => "crc8_mifare_mad",
+            Algorithm::Crc8Nrsc5 => "crc8_nrsc5",
+            Algorithm::Crc8Opensafety => "crc8_opensafety",
+            Algorithm::Crc8Rohc => "crc8_rohc",
+            Algorithm::Crc8SaeJ1850 => "crc8_sae_j1850",
+            Algorithm::Crc8Smbus => "crc8_smbus",
+            Algorithm::Crc8Tech3250 => "crc8_tech3250",
+            Algorithm::Crc8Wcdma => "crc8_wcdma",
+            Algorithm::Crc10Atm => "crc10_atm",
+            Algorithm::Crc10Cdma2000 => "crc10_cdma2000",
+            Algorithm::Crc10Gsm => "crc10_gsm",
+            Algorithm::Crc11Flexray => "crc11_flexray",
+            Algorithm::Crc11Umts => "crc11_umts",
+            Algorithm::Crc12Cdma2000 => "crc12_cdma2000",
+            Algorithm::Crc12Dect => "crc12_dect",
+            Algorithm::Crc12Gsm => "crc12_gsm",
+            Algorithm::Crc12Umts => "crc12_umts",
+            Algorithm::Crc13Bbc => "crc13_bbc",
+            Algorithm::Crc14Darc => "crc14_darc",
+            Algorithm::Crc14Gsm => "crc14_gsm",
+            Algorithm::Crc15Can => "crc15_can",
+            Algorithm::Crc15Mpt1327 => "crc15_mpt1327",
+            Algorithm::Crc16Arc => "crc16_arc",
+            Algorithm::Crc16Cdma2000 => "crc16_cdma2000",
+            Algorithm::Crc16Cms => "crc16_cms",
+            Algorithm::Crc16Dds110 => "crc16_dds110",
+            Algorithm::Crc16DectR => "crc16_dect_r",
+            Algorithm::Crc16DectX => "crc16_dect_x",
+            Algorithm::Crc16Dnp => "crc16_dnp",
+            Algorithm::Crc16En13757 => "crc16_en13757",
+            Algorithm::Crc16Genibus => "crc16_genibus",
+            Algorithm::Crc16Gsm => "crc16_gsm",
+            Algorithm::Crc16Ibm3740 => "crc16_ibm3740",
+            Algorithm::Crc16IbmSdlc => "crc16_ibm_sdlc",
+            Algorithm::Crc16IsoIec14443_3A => "crc16_iso_iec14443_3_a",
+            Algorithm::Crc16Kermit => "crc16_kermit",
+            Algorithm::Crc16Lj1200 => "crc16_lj1200",
+            Algorithm::Crc16M17 => "crc16_m17",
+            Algorithm::Crc16MaximDow => "crc16_maxim_dow",
+            Algorithm::Crc16Mcrf4xx => "crc16_mcrf4xx",
+            Algorithm::Crc16Modbus => "crc16_modbus",
+            Algorithm::Crc16Nrsc5 => "crc16_nrsc5",
+            Algorithm::Crc16OpensafetyA => "crc16_opensafety_a",
+            Algorithm::Crc16OpensafetyB => "crc16_opensafety_b",
+            Algorithm::Crc16Profibus => "crc16_profibus",
+            Algorithm::Crc16Riello => "crc16_riello",
+            Algorithm::Crc16SpiFujitsu => "crc16_spi_fujitsu",
+            Algorithm::Crc16T10Dif => "crc16_t10_dif",
+            Algorithm::Crc16Teledisk => "crc16_teledisk",
+            Algorithm::Crc16Tms37157 => "crc16_tms37157",
+            Algorithm::Crc16Umts => "crc16_umts",
+            Algorithm::Crc16Usb => "crc16_usb",
+            Algorithm::Crc16Xmodem => "crc16_xmodem",
+            Algorithm::Crc17CanFd => "crc17_can_fd",
+            Algorithm::Crc21CanFd => "crc21_can_fd",
+            Algorithm::Crc24Ble => "crc24_ble",
+            Algorithm::Crc24FlexrayA => "crc24_flexray_a",
+            Algorithm::Crc24FlexrayB => "crc24_flexray_b",
+            Algorithm::Crc24Interlaken => "crc24_interlaken",
+            Algorithm::Crc24LteA => "crc24_lte_a",
+            Algorithm::Crc24LteB => "crc24_lte_b",
+            Algorithm::Crc24Openpgp => "crc24_openpgp",
+            Algorithm::Crc24Os9 => "crc24_os9",
+            Algorithm::Crc30Cdma => "crc30_cdma",
+            Algorithm::Crc31Philips => "crc31_philips",
+            Algorithm::Crc32Aixm => "crc32_aixm",
+            Algorithm::Crc32Autosar => "crc32_autosar",
+            Algorithm::Crc32Base91D => "crc32_base91_d",
+            Algorithm::Crc32Bzip2 => "crc32_bzip2",
+            Algorithm::Crc32CdRomEdc => "crc32_cd_rom_edc",
+            Algorithm::Crc32Cksum => "crc32_cksum",
+            Algorithm::Crc32Iscsi => "crc32_iscsi",
+            Algorithm::Crc32Isohdlc => "crc32_isohdlc",
+            Algorithm::Crc32Jamcrc => "crc32_jamcrc",
+            Algorithm::Crc32Mef => "crc32_mef",
+            Algorithm::Crc32Mpeg2 => "crc32_mpeg2",
+            Algorithm::Crc32Xfer => "crc32_xfer",
+            Algorithm::Crc40Gsm => "crc40_gsm",
+            Algorithm::Crc64Ecma182 => "crc64_ecma182",
+            Algorithm::Crc64Goiso => "crc64_goiso",
+            Algorithm::Crc64Ms => "crc64_ms",
+            Algorithm::Crc64Nvme => "crc64_nvme",
+            Algorithm::Crc64Redis => "crc64_redis",
+            Algorithm::Crc64We => "crc64_we",
+            Algorithm::Crc64Xz => "crc64_xz",
+            Algorithm::Crc82Darc => "crc82_darc",
+            Algorithm::Md5 => "md5",
+            Algorithm::Ripemd160 => "ripemd160",
+            Algorithm::Ripemd320 => "ripemd320",
+            Algorithm::Whirlpool => "whirlpool",
+            Algorithm::Tiger => "tiger",
+            Algorithm::Tiger2 => "tiger2",
+            Algorithm::Streebog256 => "streebog256",
+            Algorithm::Streebog512 => "streebog512",
+            Algorithm::Sm3 => "sm3",
+            Algorithm::Md4 => "md4",
+            Algorithm::Md2 => "md2",
+            Algorithm::Sure25 => "sure25",
+            Algorithm::Xxh32 => "xxh32",
+            Algorithm::Xxh64 => "xxh64",
+            Algorithm::Xxh3_64 => "xxh3_64",
+            Algorithm::Xxh3_128 => "xxh3_128",
+            Algorithm::Fnv1a64 => "fnv1a_64",
+            Algorithm::Murmur3_128 => "murmur3_128",
+            Algorithm::Ssdeep => "ssdeep",
+            Algorithm::Tlsh => "tlsh",
+        }
+    }
 
-        let mut sha2 = Sha256::new();
-        sha2.update(data.as_slice());
-        let sha256 = hex::encode(sha2.finalize());
+    /// the CRC of `123456789` according to the catalogue of the `crc`
+    /// crate, for the algorithms in [`Algorithm::CRC`]
+    pub fn check(&self) -> Option<u128> {
+        match self {
+            Algorithm::Crc3Gsm => Some(CRC_3_GSM.check as u128),
+            Algorithm::Crc3Rohc => Some(CRC_3_ROHC.check as u128),
+            Algorithm::Crc4G704 => Some(CRC_4_G_704.check as u128),
+            Algorithm::Crc4Interlaken => Some(CRC_4_INTERLAKEN.check as u128),
+            Algorithm::Crc5EpcC1g2 => Some(CRC_5_EPC_C1G2.check as u128),
+            Algorithm::Crc5G704 => Some(CRC_5_G_704.check as u128),
+            Algorithm::Crc5Usb => Some(CRC_5_USB.check as u128),
+            Algorithm::Crc6Cdma2000a => Some(CRC_6_CDMA2000_A.check as u128),
+            Algorithm::Crc6Cdma2000b => Some(CRC_6_CDMA2000_B.check as u128),
+            Algorithm::Crc6Darc => Some(CRC_6_DARC.check as u128),
+            Algorithm::Crc6G704 => Some(CRC_6_G_704.check as u128),
+            Algorithm::Crc6Gsm => Some(CRC_6_GSM.check as u128),
+            Algorithm::Crc7Mmc => Some(CRC_7_MMC.check as u128),
+            Algorithm::Crc7Rohc => Some(CRC_7_ROHC.check as u128),
+            Algorithm::Crc7Umts => Some(CRC_7_UMTS.check as u128),
+            Algorithm::Crc8Autosar => Some(CRC_8_AUTOSAR.check as u128),
+            Algorithm::Crc8Bluetooth => Some(CRC_8_BLUETOOTH.check as u128),
+            Algorithm::Crc8Cdma2000 => Some(CRC_8_CDMA2000.check as u128),
+            Algorithm::Crc8Darc => Some(CRC_8_DARC.check as u128),
+            Algorithm::Crc8DvbS2 => Some(CRC_8_DVB_S2.check as u128),
+            Algorithm::Crc8GsmA => Some(CRC_8_GSM_A.check as u128),
+            Algorithm::Crc8GsmB => Some(CRC_8_GSM_B.check as u128),
+            Algorithm::Crc8Hitag => Some(CRC_8_HITAG.check as u128),
+            Algorithm::Crc8I432_1 => Some(CRC_8_I_432_1.check as u128),
+            Algorithm::Crc8ICode => Some(CRC_8_I_CODE.check as u128),
+            Algorithm::Crc8Lte => Some(CRC_8_LTE.check as u128),
+            Algorithm::Crc8MaximDow => Some(CRC_8_MAXIM_DOW.check as u128),
+            Algorithm::Crc8MifareMad => Some(CRC_8_MIFARE_MAD.check as u128),
+            Algorithm::Crc8Nrsc5 => Some(CRC_8_NRSC_5.check as u128),
+            Algorithm::Crc8Opensafety => Some(CRC_8_OPENSAFETY.check as u128),
+            Algorithm::Crc8Rohc => Some(CRC_8_ROHC.check as u128),
+            Algorithm::Crc8SaeJ1850 => Some(CRC_8_SAE_J1850.check as u128),
+            Algorithm::Crc8Smbus => Some(CRC_8_SMBUS.check as u128),
+            Algorithm::Crc8Tech3250 => Some(CRC_8_TECH_3250.check as u128),
+            Algorithm::Crc8Wcdma => Some(CRC_8_WCDMA.check as u128),
+            Algorithm::Crc10Atm => Some(CRC_10_ATM.check as u128),
+            Algorithm::Crc10Cdma2000 => Some(CRC_10_CDMA2000.check as u128),
+            Algorithm::Crc10Gsm => Some(CRC_10_GSM.check as u128),
+            Algorithm::Crc11Flexray => Some(CRC_11_FLEXRAY.check as u128),
+            Algorithm::Crc11Umts => Some(CRC_11_UMTS.check as u128),
+            Algorithm::Crc12Cdma2000 => Some(CRC_12_CDMA2000.check as u128),
+            Algorithm::Crc12Dect => Some(CRC_12_DECT.check as u128),
+            Algorithm::Crc12Gsm => Some(CRC_12_GSM.check as u128),
+            Algorithm::Crc12Umts => Some(CRC_12_UMTS.check as u128),
+            Algorithm::Crc13Bbc => Some(CRC_13_BBC.check as u128),
+            Algorithm::Crc14Darc => Some(CRC_14_DARC.check as u128),
+            Algorithm::Crc14Gsm => Some(CRC_14_GSM.check as u128),
+            Algorithm::Crc15Can => Some(CRC_15_CAN.check as u128),
+            Algorithm::Crc15Mpt1327 => Some(CRC_15_MPT1327.check as u128),
+            Algorithm::Crc16Arc => Some(CRC_16_ARC.check as u128),
+            Algorithm::Crc16Cdma2000 => Some(CRC_16_CDMA2000.check as u128),
+            Algorithm::Crc16Cms => Some(CRC_16_CMS.check as u128),
+            Algorithm::Crc16Dds110 => Some(CRC_16_DDS_110.check as u128),
+            Algorithm::Crc16DectR => Some(CRC_16_DECT_R.check as u128),
+            Algorithm::Crc16DectX => Some(CRC_16_DECT_X.check as u128),
+            Algorithm::Crc16Dnp => Some(CRC_16_DNP.check as u128),
+            Algorithm::Crc16En13757 => Some(CRC_16_EN_13757.check as u128),
+            Algorithm::Crc16Genibus => Some(CRC_16_GENIBUS.check as u128),
+            Algorithm::Crc16Gsm => Some(CRC_16_GSM.check as u128),
+            Algorithm::Crc16Ibm3740 => Some(CRC_16_IBM_3740.check as u128),
+            Algorithm::Crc16IbmSdlc => Some(CRC_16_IBM_SDLC.check as u128),
+            Algorithm::Crc16IsoIec14443_3A => Some(CRC_16_ISO_IEC_14443_3_A.check as u128),
+            Algorithm::Crc16Kermit => Some(CRC_16_KERMIT.check as u128),
+            Algorithm::Crc16Lj1200 => Some(CRC_16_LJ1200.check as u128),
+            Algorithm::Crc16M17 => Some(CRC_16_M17.check as u128),
+            Algorithm::Crc16MaximDow => Some(CRC_16_MAXIM_DOW.check as u128),
+            Algorithm::Crc16Mcrf4xx => Some(CRC_16_MCRF4XX.check as u128),
+            Algorithm::Crc16Modbus => Some(CRC_16_MODBUS.check as u128),
+            Algorithm::Crc16Nrsc5 => Some(CRC_16_NRSC_5.check as u128),
+            Algorithm::Crc16OpensafetyA => Some(CRC_16_OPENSAFETY_A.check as u128),
+            Algorithm::Crc16OpensafetyB => Some(CRC_16_OPENSAFETY_B.check as u128),
+            Algorithm::Crc16Profibus => Some(CRC_16_PROFIBUS.check as u128),
+            Algorithm::Crc16Riello => Some(CRC_16_RIELLO.check as u128),
+            Algorithm::Crc16SpiFujitsu => Some(CRC_16_SPI_FUJITSU.check as u128),
+            Algorithm::Crc16T10Dif => Some(CRC_16_T10_DIF.check as u128),
+            Algorithm::Crc16Teledisk => Some(CRC_16_TELEDISK.check as u128),
+            Algorithm::Crc16Tms37157 => Some(CRC_16_TMS37157.check as u128),
+            Algorithm::Crc16Umts => Some(CRC_16_UMTS.check as u128),
+            Algorithm::Crc16Usb => Some(CRC_16_USB.check as u128),
+            Algorithm::Crc16Xmodem => Some(CRC_16_XMODEM.check as u128),
+            Algorithm::Crc17CanFd => Some(CRC_17_CAN_FD.check as u128),
+            Algorithm::Crc21CanFd => Some(CRC_21_CAN_FD.check as u128),
+            Algorithm::Crc24Ble => Some(CRC_24_BLE.check as u128),
+            Algorithm::Crc24FlexrayA => Some(CRC_24_FLEXRAY_A.check as u128),
+            Algorithm::Crc24FlexrayB => Some(CRC_24_FLEXRAY_B.check as u128),
+            Algorithm::Crc24Interlaken => Some(CRC_24_INTERLAKEN.check as u128),
+            Algorithm::Crc24LteA => Some(CRC_24_LTE_A.check as u128),
+            Algorithm::Crc24LteB => Some(CRC_24_LTE_B.check as u128),
+            Algorithm::Crc24Openpgp => Some(CRC_24_OPENPGP.check as u128),
+            Algorithm::Crc24Os9 => Some(CRC_24_OS_9.check as u128),
+            Algorithm::Crc30Cdma => Some(CRC_30_CDMA.check as u128),
+            Algorithm::Crc31Philips => Some(CRC_31_PHILIPS.check as u128),
+            Algorithm::Crc32Aixm => Some(CRC_32_AIXM.check as u128),
+            Algorithm::Crc32Autosar => Some(CRC_32_AUTOSAR.check as u128),
+            Algorithm::Crc32Base91D => Some(CRC_32_BASE91_D.check as u128),
+            Algorithm::Crc32Bzip2 => Some(CRC_32_BZIP2.check as u128),
+            Algorithm::Crc32CdRomEdc => Some(CRC_32_CD_ROM_EDC.check as u128),
+            Algorithm::Crc32Cksum => Some(CRC_32_CKSUM.check as u128),
+            Algorithm::Crc32Iscsi => Some(CRC_32_ISCSI.check as u128),
+            Algorithm::Crc32Isohdlc => Some(CRC_32_ISO_HDLC.check as u128),
+            Algorithm::Crc32Jamcrc => Some(CRC_32_JAMCRC.check as u128),
+            Algorithm::Crc32Mef => Some(CRC_32_MEF.check as u128),
+            Algorithm::Crc32Mpeg2 => Some(CRC_32_MPEG_2.check as u128),
+            Algorithm::Crc32Xfer => Some(CRC_32_XFER.check as u128),
+            Algorithm::Crc40Gsm => Some(CRC_40_GSM.check as u128),
+            Algorithm::Crc64Ecma182 => Some(CRC_64_ECMA_182.check as u128),
+            Algorithm::Crc64Goiso => Some(CRC_64_GO_ISO.check as u128),
+            Algorithm::Crc64Ms => Some(CRC_64_MS.check as u128),
+            Algorithm::Crc64Nvme => Some(CRC_64_NVME.check as u128),
+            Algorithm::Crc64Redis => Some(CRC_64_REDIS.check as u128),
+            Algorithm::Crc64We => Some(CRC_64_WE.check as u128),
+            Algorithm::Crc64Xz => Some(CRC_64_XZ.check as u128),
+            Algorithm::Crc82Darc => Some(CRC_82_DARC.check),
+            _ => None,
+        }
+    }
 
-        let mut sha2 = Sha384::new();
-        sha2.update(data.as_slice());
-        let sha384 = hex::encode(sha2.finalize());
+    /// name of the `crc::CRC_*` catalogue entry of a CRC, which is
+    /// also accepted by [`Algorithm::from_str`]
+    pub fn catalogue(&self) -> Option<&'static str> {
+        match self {
+            Algorithm::Crc3Gsm => Some("CRC_3_GSM"),
+            Algorithm::Crc3Rohc => Some("CRC_3_ROHC"),
+            Algorithm::Crc4G704 => Some("CRC_4_G_704"),
+            Algorithm::Crc4Interlaken => Some("CRC_4_INTERLAKEN"),
+            Algorithm::Crc5EpcC1g2 => Some("CRC_5_EPC_C1G2"),
+            Algorithm::Crc5G704 => Some("CRC_5_G_704"),
+            Algorithm::Crc5Usb => Some("CRC_5_USB"),
+            Algorithm::Crc6Cdma2000a => Some("CRC_6_CDMA2000_A"),
+            Algorithm::Crc6Cdma2000b => Some("CRC_6_CDMA2000_B"),
+            Algorithm::Crc6Darc => Some("CRC_6_DARC"),
+            Algorithm::Crc6G704 => Some("CRC_6_G_704"),
+            Algorithm::Crc6Gsm => Some("CRC_6_GSM"),
+            Algorithm::Crc7Mmc => Some("CRC_7_MMC"),
+            Algorithm::Crc7Rohc => Some("CRC_7_ROHC"),
+            Algorithm::Crc7Umts => Some("CRC_7_UMTS"),
+            Algorithm::Crc8Autosar => Some("CRC_8_AUTOSAR"),
+            Algorithm::Crc8Bluetooth => Some("CRC_8_BLUETOOTH"),
+            Algorithm::Crc8Cdma2000 => Some("CRC_8_CDMA2000"),
+            Algorithm::Crc8Darc => Some("CRC_8_DARC"),
+            Algorithm::Crc8DvbS2 => Some("CRC_8_DVB_S2"),
+            Algorithm::Crc8GsmA => Some("CRC_8_GSM_A"),
+            Algorithm::Crc8GsmB => Some("CRC_8_GSM_B"),
+            Algorithm::Crc8Hitag => Some("CRC_8_HITAG"),
+            Algorithm::Crc8I432_1 => Some("CRC_8_I_432_1"),
+            Algorithm::Crc8ICode => Some("CRC_8_I_CODE"),
+            Algorithm::Crc8Lte => Some("CRC_8_LTE"),
+            Algorithm::Crc8MaximDow => Some("CRC_8_MAXIM_DOW"),
+            Algorithm::Crc8MifareMad => Some("CRC_8_MIFARE_MAD"),
+            Algorithm::Crc8Nrsc5 => Some("CRC_8_NRSC_5"),
+            Algorithm::Crc8Opensafety => Some("CRC_8_OPENSAFETY"),
+            Algorithm::Crc8Rohc => Some("CRC_8_ROHC"),
+            Algorithm::Crc8SaeJ1850 => Some("CRC_8_SAE_J1850"),
+            Algorithm::Crc8Smbus => Some("CRC_8_SMBUS"),
+            Algorithm::Crc8Tech3250 => Some("CRC_8_TECH_3250"),
+            Algorithm::Crc8Wcdma => Some("CRC_8_WCDMA"),
+            Algorithm::Crc10Atm => Some("CRC_10_ATM"),
+            Algorithm::Crc10Cdma2000 => Some("CRC_10_CDMA2000"),
+            Algorithm::Crc10Gsm => Some("CRC_10_GSM"),
+            Algorithm::Crc11Flexray => Some("CRC_11_FLEXRAY"),
+            Algorithm::Crc11Umts => Some("CRC_11_UMTS"),
+            Algorithm::Crc12Cdma2000 => Some("CRC_12_CDMA2000"),
+            Algorithm::Crc12Dect => Some("CRC_12_DECT"),
+            Algorithm::Crc12Gsm => Some("CRC_12_GSM"),
+            Algorithm::Crc12Umts => Some("CRC_12_UMTS"),
+            Algorithm::Crc13Bbc => Some("CRC_13_BBC"),
+            Algorithm::Crc14Darc => Some("CRC_14_DARC"),
+            Algorithm::Crc14Gsm => Some("CRC_14_GSM"),
+            Algorithm::Crc15Can => Some("CRC_15_CAN"),
+            Algorithm::Crc15Mpt1327 => Some("CRC_15_MPT1327"),
+            Algorithm::Crc16Arc => Some("CRC_16_ARC"),
+            Algorithm::Crc16Cdma2000 => Some("CRC_16_CDMA2000"),
+            Algorithm::Crc16Cms => Some("CRC_16_CMS"),
+            Algorithm::Crc16Dds110 => Some("CRC_16_DDS_110"),
+            Algorithm::Crc16DectR => Some("CRC_16_DECT_R"),
+            Algorithm::Crc16DectX => Some("CRC_16_DECT_X"),
+            Algorithm::Crc16Dnp => Some("CRC_16_DNP"),
+            Algorithm::Crc16En13757 => Some("CRC_16_EN_13757"),
+            Algorithm::Crc16Genibus => Some("CRC_16_GENIBUS"),
+            Algorithm::Crc16Gsm => Some("CRC_16_GSM"),
+            Algorithm::Crc16Ibm3740 => Some("CRC_16_IBM_3740"),
+            Algorithm::Crc16IbmSdlc => Some("CRC_16_IBM_SDLC"),
+            Algorithm::Crc16IsoIec14443_3A => Some("CRC_16_ISO_IEC_14443_3_A"),
+            Algorithm::Crc16Kermit => Some("CRC_16_KERMIT"),
+            Algorithm::Crc16Lj1200 => Some("CRC_16_LJ1200"),
+            Algorithm::Crc16M17 => Some("CRC_16_M17"),
+            Algorithm::Crc16MaximDow => Some("CRC_16_MAXIM_DOW"),
+            Algorithm::Crc16Mcrf4xx => Some("CRC_16_MCRF4XX"),
+            Algorithm::Crc16Modbus => Some("CRC_16_MODBUS"),
+            Algorithm::Crc16Nrsc5 => Some("CRC_16_NRSC_5"),
+            Algorithm::Crc16OpensafetyA => Some("CRC_16_OPENSAFETY_A"),
+            Algorithm::Crc16OpensafetyB => Some("CRC_16_OPENSAFETY_B"),
+            Algorithm::Crc16Profibus => Some("CRC_16_PROFIBUS"),
+            Algorithm::Crc16Riello => Some("CRC_16_RIELLO"),
+            Algorithm::Crc16SpiFujitsu => Some("CRC_16_SPI_FUJITSU"),
+            Algorithm::Crc16T10Dif => Some("CRC_16_T10_DIF"),
+            Algorithm::Crc16Teledisk => Some("CRC_16_TELEDISK"),
+            Algorithm::Crc16Tms37157 => Some("CRC_16_TMS37157"),
+            Algorithm::Crc16Umts => Some("CRC_16_UMTS"),
+            Algorithm::Crc16Usb => Some("CRC_16_USB"),
+            Algorithm::Crc16Xmodem => Some("CRC_16_XMODEM"),
+            Algorithm::Crc17CanFd => Some("CRC_17_CAN_FD"),
+            Algorithm::Crc21CanFd => Some("CRC_21_CAN_FD"),
+            Algorithm::Crc24Ble => Some("CRC_24_BLE"),
+            Algorithm::Crc24FlexrayA => Some("CRC_24_FLEXRAY_A"),
+            Algorithm::Crc24FlexrayB => Some("CRC_24_FLEXRAY_B"),
+            Algorithm::Crc24Interlaken => Some("CRC_24_INTERLAKEN"),
+            Algorithm::Crc24LteA => Some("CRC_24_LTE_A"),
+            Algorithm::Crc24LteB => Some("CRC_24_LTE_B"),
+            Algorithm::Crc24Openpgp => Some("CRC_24_OPENPGP"),
+            Algorithm::Crc24Os9 => Some("CRC_24_OS_9"),
+            Algorithm::Crc30Cdma => Some("CRC_30_CDMA"),
+            Algorithm::Crc31Philips => Some("CRC_31_PHILIPS"),
+            Algorithm::Crc32Aixm => Some("CRC_32_AIXM"),
+            Algorithm::Crc32Autosar => Some("CRC_32_AUTOSAR"),
+            Algorithm::Crc32Base91D => Some("CRC_32_BASE91_D"),
+            Algorithm::Crc32Bzip2 => Some("CRC_32_BZIP2"),
+            Algorithm::Crc32CdRomEdc => Some("CRC_32_CD_ROM_EDC"),
+            Algorithm::Crc32Cksum => Some("CRC_32_CKSUM"),
+            Algorithm::Crc32Iscsi => Some("CRC_32_ISCSI"),
+            Algorithm::Crc32Isohdlc => Some("CRC_32_ISO_HDLC"),
+            Algorithm::Crc32Jamcrc => Some("CRC_32_JAMCRC"),
+            Algorithm::Crc32Mef => Some("CRC_32_MEF"),
+            Algorithm::Crc32Mpeg2 => Some("CRC_32_MPEG_2"),
+            Algorithm::Crc32Xfer => Some("CRC_32_XFER"),
+            Algorithm::Crc40Gsm => Some("CRC_40_GSM"),
+            Algorithm::Crc64Ecma182 => Some("CRC_64_ECMA_182"),
+            Algorithm::Crc64Goiso => Some("CRC_64_GO_ISO"),
+            Algorithm::Crc64Ms => Some("CRC_64_MS"),
+            Algorithm::Crc64Nvme => Some("CRC_64_NVME"),
+            Algorithm::Crc64Redis => Some("CRC_64_REDIS"),
+            Algorithm::Crc64We => Some("CRC_64_WE"),
+            Algorithm::Crc64Xz => Some("CRC_64_XZ"),
+            Algorithm::Crc82Darc => Some("CRC_82_DARC"),
+            _ => None,
+        }
+    }
 
-        let mut sha2 = Sha512::new();
-        sha2.update(data.as_slice());
-        let sha512 = hex::encode(sha2.finalize());
+    /// the seed of non-cryptographic algorithms when none is given to
+    /// [`Digester::with_seed`]:
+    ///
+    /// - `xxh32`, `xxh64`, `xxh3_64` and `xxh3_128`: `0`
+    /// - `murmur3_128`: `0`, only the lower 32 bits of a seed are used
+    /// - `fnv1a_64`: the FNV offset basis `0xcbf29ce484222325`, which
+    ///   is the initial state of the hash
+    ///
+    /// [`None`] means the algorithm takes no seed.
+    pub fn default_seed(&self) -> Option<u64> {
+        match self {
+            Algorithm::Xxh32
+            | Algorithm::Xxh64
+            | Algorithm::Xxh3_64
+            | Algorithm::Xxh3_128
+            | Algorithm::Murmur3_128 => Some(0),
+            Algorithm::Fnv1a64 => Some(0xCBF29CE484222325),
+            _ => None,
+        }
+    }
 
-        let mut ripemd160 = Ripemd160::new();
-        ripemd160.update(data.as_slice());
-        let ripemd160 = hex::encode(ripemd160.finalize());
+    /// every algorithm, including the ones left out of [`Algorithm::ALL`]
+    pub fn every() -> impl Iterator<Item = Algorithm> {
+        let mut every = Vec::<Algorithm>::new();
+        let sets = [
+            Algorithm::ALL,
+            Algorithm::CRC,
+            Algorithm::NONCRYPTO,
+            Algorithm::FUZZY,
+            Algorithm::XOF,
+            Algorithm::LEGACY,
+        ];
+        for algorithm in sets.concat() {
+            if !every.contains(&algorithm) {
+                every.push(algorithm);
+            }
+        }
+        every.into_iter()
+    }
 
-        let mut ripemd320 = Ripemd320::new();
-        ripemd320.update(data.as_slice());
-        let ripemd320 = hex::encode(ripemd320.finalize());
+    /// code of the algorithm in the multicodec table of multiformats
+    pub fn multihash(&self) -> Option<u64> {
+        match self {
+            Algorithm::Sha1 => Some(0x11),
+            Algorithm::Sha256 => Some(0x12),
+            Algorithm::Sha512 => Some(0x13),
+            Algorithm::Sha3_512 => Some(0x14),
+            Algorithm::Sha3_384 => Some(0x15),
+            Algorithm::Sha3_256 => Some(0x16),
+            Algorithm::Sha3_224 => Some(0x17),
+            Algorithm::Shake128 => Some(0x18),
+            Algorithm::Shake256 => Some(0x19),
+            Algorithm::Keccak224 => Some(0x1A),
+            Algorithm::Keccak256 => Some(0x1B),
+            Algorithm::Blake3 => Some(0x1E),
+            Algorithm::Sha384 => Some(0x20),
+            Algorithm::Md4 => Some(0xD4),
+            Algorithm::Md5 => Some(0xD5),
+            Algorithm::Sha224 => Some(0x1013),
+            Algorithm::Sha512_224 => Some(0x1014),
+            Algorithm::Sha512_256 => Some(0x1015),
+            Algorithm::Ripemd160 => Some(0x1053),
+            Algorithm::Ripemd320 => Some(0x1055),
+            Algorithm::Blake2b512 => Some(0xB240),
+            Algorithm::Blake2s256 => Some(0xB260),
+            Algorithm::Sm3 => Some(0x534D),
+            _ => None,
+        }
+    }
 
-        let mut sure25 = Sure25::new();
-        sure25.update(data.as_slice());
-        let sure25 = hex::encode(sure25.finalize());
+    /// name of the algorithm in BSD-style checksum lists as written by
+    /// `sha256sum --tag`, `b2sum --tag`, `xxhsum --tag` and the `md5`
+    /// and `sha256` of the BSDs, see [`crate::Sums::Bsd`]
+    pub fn tag(&self) -> Option<&'static str> {
+        match self {
+            Algorithm::Md5 => Some("MD5"),
+            Algorithm::Sha1 => Some("SHA1"),
+            Algorithm::Sha224 => Some("SHA224"),
+            Algorithm::Sha256 => Some("SHA256"),
+            Algorithm::Sha384 => Some("SHA384"),
+            Algorithm::Sha512 => Some("SHA512"),
+            Algorithm::Sha512_224 => Some("SHA512t224"),
+            Algorithm::Sha512_256 => Some("SHA512t256"),
+            Algorithm::Sha3_224 => Some("SHA3-224"),
+            Algorithm::Sha3_256 => Some("SHA3-256"),
+            Algorithm::Sha3_384 => Some("SHA3-384"),
+            Algorithm::Sha3_512 => Some("SHA3-512"),
+            Algorithm::Blake2b512 => Some("BLAKE2b"),
+            Algorithm::Ripemd160 => Some("RMD160"),
+            Algorithm::Sm3 => Some("SM3"),
+            Algorithm::Xxh32 => Some("XXH32"),
+            Algorithm::Xxh64 => Some("XXH64"),
+            Algorithm::Xxh3_64 => Some("XXH3"),
+            Algorithm::Xxh3_128 => Some("XXH128"),
+            _ => None,
+        }
+    }
 
-        let md5 = format!("{:064x}", md5_compute(data.as_slice()));
+    /// default output length in bytes of the extendable-output
+    /// functions, i.e.: twice their security strength in bits:
+    ///
+    /// - 32 for `shake128` and `cshake128`
+    /// - 64 for `shake256` and `cshake256`
+    ///
+    /// [`None`] means the algorithm has a fixed-length output.
+    pub fn default_length(&self) -> Option<usize> {
+        match self {
+            Algorithm::Shake128 | Algorithm::Cshake128 => Some(32),
+            Algorithm::Shake256 | Algorithm::Cshake256 => Some(64),
+            _ => None,
+        }
+    }
 
-        let adler32 = adler32(data.as_slice())
-            .map(|n| format!("{:08x}", n))
-            .unwrap_or_else(|e| format!("adler32 error: {}", e));
+    /// bits of the digests of the algorithm, which are rendered in as
+    /// many hex digits as they take, e.g.: 3 for `crc10_atm`. [`None`]
+    /// for the fuzzy digests and the extendable-output functions, whose
+    /// length is given to the [`Digester`]
+    pub fn bits(&self) -> Option<usize> {
+        if self.default_length().is_some() {
+            return None;
+        }
+        self.checksum(&Digester::new(vec![*self])).bits()
+    }
 
-        // CRC
-        let crc = Crc::<u32>::new(&CRC_32_BZIP2);
-        let crc32_bzip2 = format!("{:08x}", crc.checksum(data.as_slice()));
+    /// whether the state of a digest in progress can be saved to a
+    /// [`Checkpoint`], e.g.: MD5, SHA-1, SHA-2, SHA-3 and the CRCs
+    pub fn resumable(&self) -> bool {
+        self.resumable_checksum(&Digester::new(vec![*self])).state().is_some()
+    }
 
-        let crc = Crc::<u32>::new(&CRC_32_JAMCRC);
-        let crc32_jamcrc = format!("{:08x}", crc.checksum(data.as_slice()));
+    /// named sets of algorithms: "all", "fast", "forensic", "fuzzy",
+    /// "noncrypto", "xof", "legacy" and "crc-all"
+    pub fn preset(name: &str) -> Option<&'static [Algorithm]> {
+        match name {
+            "all" => Some(Algorithm::ALL),
+            "fast" => Some(Algorithm::FAST),
+            "forensic" => Some(Algorithm::FORENSIC),
+            "fuzzy" => Some(Algorithm::FUZZY),
+            "noncrypto" => Some(Algorithm::NONCRYPTO),
+            "xof" => Some(Algorithm::XOF),
+            "legacy" => Some(Algorithm::LEGACY),
+            "crc-all" | "crc_all" => Some(Algorithm::CRC),
+            _ => None,
+        }
+    }
 
-        let crc = Crc::<u32>::new(&CRC_32_ISCSI);
-        let crc32_iscsi = format!("{:08x}", crc.checksum(data.as_slice()));
+    /// resolves a list of algorithm and preset names, e.g.: `["sha256",
+    /// "md5", "crc32_iscsi"]` or `["fast", "sha512"]`, into algorithms
+    /// without duplicates.
+    pub fn select(names: &[impl AsRef<str>]) -> Result<Vec<Algorithm>, Error> {
+        let mut algorithms = Vec::<Algorithm>::new();
+        for name in names {
+            let name = name.as_ref().trim().to_lowercase().replace('-', "_");
+            let selected = match Algorithm::preset(&name) {
+                Some(preset) => preset.to_vec(),
+                None => vec![name.parse::<Algorithm>()?],
+            };
+            for algorithm in selected {
+                if !algorithms.contains(&algorithm) {
+                    algorithms.push(algorithm);
+                }
+            }
+        }
+        Ok(algorithms)
+    }
 
-        let crc = Crc::<u32>::new(&CRC_32_MPEG_2);
-        let crc32_mpeg2 = format!("{:08x}", crc.checksum(data.as_slice()));
+    /// keyed counterpart of [`Algorithm::checksum`] for the algorithms
+    /// in [`Algorithm::KEYED`]
+    fn keyed_checksum(&self, key: &Key) -> Option<Box<dyn Checksum>> {
+        let secret = key.secret();
+        Some(match self {
+            Algorithm::Sha224 => Box::new(Hmac::<Sha224>::new(secret)),
+            Algorithm::Sha256 => Box::new(Hmac::<Sha256>::new(secret)),
+            Algorithm::Sha384 => Box::new(Hmac::<Sha384>::new(secret)),
+            Algorithm::Sha512 => Box::new(Hmac::<Sha512>::new(secret)),
+            Algorithm::Sha512_224 => Box::new(Hmac::<Sha512_224>::new(secret)),
+            Algorithm::Sha512_256 => Box::new(Hmac::<Sha512_256>::new(secret)),
+            Algorithm::Sha3_224 => Box::new(Hmac::<Sha3_224>::new(secret)),
+            Algorithm::Sha3_256 => Box::new(Hmac::<Sha3_256>::new(secret)),
+            Algorithm::Sha3_384 => Box::new(Hmac::<Sha3_384>::new(secret)),
+            Algorithm::Sha3_512 => Box::new(Hmac::<Sha3_512>::new(secret)),
+            Algorithm::Ripemd160 => Box::new(Hmac::<Ripemd160>::new(secret)),
+            Algorithm::Ripemd320 => Box::new(Hmac::<Ripemd320>::new(secret)),
+            Algorithm::Blake3 => Box::new(Blake3::new_keyed(&key.blake3())),
+            _ => return None,
+        })
+    }
 
-        let crc = Crc::<u16>::new(&CRC_11_FLEXRAY);
-        let crc11_flexray = format!("{:04x}", crc.checksum(data.as_slice()));
+    /// the checksum of [`Algorithm::checksum`] or, for the algorithms
+    /// whose state the hashers of their crates don't export, one of
+    /// [`crate::resumable`] whose state can be saved to a [`Checkpoint`]
+    fn resumable_checksum(&self, digester: &Digester) -> Box<dyn Checksum> {
+        let length = digester.xof_length(*self).unwrap_or_default();
+        match self {
+            Algorithm::Md5 => Box::new(BlockDigest::new(resumable::MD5, 16)),
+            Algorithm::Sha1 => Box::new(BlockDigest::new(resumable::SHA1, 20)),
+            Algorithm::Sha224 => Box::new(BlockDigest::new(resumable::SHA224, 28)),
+            Algorithm::Sha256 => Box::new(BlockDigest::new(resumable::SHA256, 32)),
+            Algorithm::Sha384 => Box::new(BlockDigest::new(resumable::SHA384, 48)),
+            Algorithm::Sha512 => Box::new(BlockDigest::new(resumable::SHA512, 64)),
+            Algorithm::Sha512_224 => Box::new(BlockDigest::new(resumable::SHA512_224, 28)),
+            Algorithm::Sha512_256 => Box::new(BlockDigest::new(resumable::SHA512_256, 32)),
+            Algorithm::Sha3_224 => Box::new(Sponge::new(144, 0x06, 28)),
+            Algorithm::Sha3_256 => Box::new(Sponge::new(136, 0x06, 32)),
+            Algorithm::Sha3_384 => Box::new(Sponge::new(104, 0x06, 48)),
+            Algorithm::Sha3_512 => Box::new(Sponge::new(72, 0x06, 64)),
+            Algorithm::Keccak224 => Box::new(Sponge::new(144, 0x01, 28)),
+            Algorithm::Keccak256 => Box::new(Sponge::new(136, 0x01, 32)),
+            Algorithm::Shake128 => Box::new(Sponge::new(168, 0x1f, length)),
+            Algorithm::Shake256 => Box::new(Sponge::new(136, 0x1f, length)),
+            _ => self.checksum(digester),
+        }
+    }
 
-        let crc = Crc::<u8>::new(&CRC_3_GSM);
-        let crc3_gsm = format!("{:02x}", crc.checksum(data.as_slice()));
+    fn checksum(&self, digester: &Digester) -> Box<dyn Checksum> {
+        let seed = digester.seed(*self);
+        let length = digester.xof_length(*self).unwrap_or_default();
+        let customization = digester.customization.as_bytes();
+        match self {
+            Algorithm::Sha1 => Box::new(Hex(Sha1::new())),
+            Algorithm::Sha512 => Box::new(Hex(Sha512::new())),
+            Algorithm::Sha224 => Box::new(Hex(Sha224::new())),
+            Algorithm::Sha384 => Box::new(Hex(Sha384::new())),
+            Algorithm::Sha256 => Box::new(Hex(Sha256::new())),
+            Algorithm::Sha512_224 => Box::new(Hex(Sha512_224::new())),
+            Algorithm::Sha512_256 => Box::new(Hex(Sha512_256::new())),
+            Algorithm::Keccak256Full => Box::new(Hex(Keccak256Full::new())),
+            Algorithm::Keccak256 => Box::new(Hex(Keccak256::new())),
+            Algorithm::Keccak224 => Box::new(Hex(Keccak224::new())),
+            Algorithm::Sha3_224 => Box::new(Hex(Sha3_224::new())),
+            Algorithm::Sha3_256 => Box::new(Hex(Sha3_256::new())),
+            Algorithm::Sha3_384 => Box::new(Hex(Sha3_384::new())),
+            Algorithm::Sha3_512 => Box::new(Hex(Sha3_512::new())),
+            Algorithm::Shake128 => Box::new(Xof(Shake128::default(), length)),
+            Algorithm::Shake256 => Box::new(Xof(Shake256::default(), length)),
+            Algorithm::Cshake128 =>
+                Box::new(Xof(CShake128::from_core(CShake128Core::new(customization)), length)),
+            Algorithm::Cshake256 =>
+                Box::new(Xof(CShake256::from_core(CShake256Core::new(customization)), length)),
+            Algorithm::Blake2b512 => Box::new(Hex(Blake2b512::new())),
+            Algorithm::Blake2s256 => Box::new(Hex(Blake2s256::new())),
+            Algorithm::Blake3 => Box::new(digester.blake3.hasher()),
+            Algorithm::Adler32 => Box::new(RollingAdler32::new()),
+            Algorithm::Crc3Gsm => Box::new(CrcChecksum::new(&CRC3_GSM)),
+            Algorithm::Crc3Rohc => Box::new(CrcChecksum::new(&CRC3_ROHC)),
+            Algorithm::Crc4G704 => Box::new(CrcChecksum::new(&CRC4_G704)),
+            Algorithm::Crc4Interlaken => Box::new(CrcChecksum::new(&CRC4_INTERLAKEN)),
+            Algorithm::Crc5EpcC1g2 => Box::new(CrcChecksum::new(&CRC5_EPC_C1G2)),
+            Algorithm::Crc5G704 => Box::new(CrcChecksum::new(&CRC5_G704)),
+            Algorithm::Crc5Usb => Box::new(CrcChecksum::new(&CRC5_USB)),
+            Algorithm::Crc6Gsm => Box::new(CrcChecksum::new(&CRC6_GSM)),
+            Algorithm::Crc7Mmc => Box::new(CrcChecksum::new(&CRC7_MMC)),
+            Algorithm::Crc7Rohc => Box::new(CrcChecksum::new(&CRC7_ROHC)),
+            Algorithm::Crc7Umts => Box::new(CrcChecksum::new(&CRC7_UMTS)),
+            Algorithm::Crc8Autosar => Box::new(CrcChecksum::new(&CRC8_AUTOSAR)),
+            Algorithm::Crc8Bluetooth => Box::new(CrcChecksum::new(&CRC8_BLUETOOTH)),
+            Algorithm::Crc8Cdma2000 => Box::new(CrcChecksum::new(&CRC8_CDMA2000)),
+            Algorithm::Crc8Darc => Box::new(CrcChecksum::new(&CRC8_DARC)),
+            Algorithm::Crc8DvbS2 => Box::new(CrcChecksum::new(&CRC8_DVB_S2)),
+            Algorithm::Crc8GsmA => Box::new(CrcChecksum::new(&CRC8_GSM_A)),
+            Algorithm::Crc8GsmB => Box::new(CrcChecksum::new(&CRC8_GSM_B)),
+            Algorithm::Crc8Hitag => Box::new(CrcChecksum::new(&CRC8_HITAG)),
+            Algorithm::Crc8I432_1 => Box::new(CrcChecksum::new(&CRC8_I432_1)),
+            Algorithm::Crc8ICode => Box::new(CrcChecksum::new(&CRC8_I_CODE)),
+            Algorithm::Crc6Cdma2000a => Box::new(CrcChecksum::new(&CRC6_CDMA_2000A)),
+            Algorithm::Crc6Cdma2000b => Box::new(CrcChecksum::new(&CRC6_CDMA_2000B)),
+            Algorithm::Crc6Darc => Box::new(CrcChecksum::new(&CRC6_DARC)),
+            Algorithm::Crc6G704 => Box::new(CrcChecksum::new(&CRC6_G704)),
+            Algorithm::Crc8Lte => Box::new(CrcChecksum::new(&CRC8_LTE)),
+            Algorithm::Crc8MaximDow => Box::new(CrcChecksum::new(&CRC8_MAXIM_DOW)),
+            Algorithm::Crc8MifareMad => Box::new(CrcChecksum::new(&CRC8_MIFARE_MAD)),
+            Algorithm::Crc8Nrsc5 => Box::new(CrcChecksum::new(&CRC8_NRSC5)),
+            Algorithm::Crc8Opensafety => Box::new(CrcChecksum::new(&CRC8_OPENSAFETY)),
+            Algorithm::Crc8Rohc => Box::new(CrcChecksum::new(&CRC8_ROHC)),
+            Algorithm::Crc8SaeJ1850 => Box::new(CrcChecksum::new(&CRC8_SAE_J1850)),
+            Algorithm::Crc8Smbus => Box::new(CrcChecksum::new(&CRC8_SMBUS)),
+            Algorithm::Crc8Tech3250 => Box::new(CrcChecksum::new(&CRC8_TECH3250)),
+            Algorithm::Crc8Wcdma => Box::new(CrcChecksum::new(&CRC8_WCDMA)),
+            Algorithm::Crc10Atm => Box::new(CrcChecksum::new(&CRC10_ATM)),
+            Algorithm::Crc10Cdma2000 => Box::new(CrcChecksum::new(&CRC10_CDMA2000)),
+            Algorithm::Crc10Gsm => Box::new(CrcChecksum::new(&CRC10_GSM)),
+            Algorithm::Crc11Flexray => Box::new(CrcChecksum::new(&CRC11_FLEXRAY)),
+            Algorithm::Crc11Umts => Box::new(CrcChecksum::new(&CRC11_UMTS)),
+            Algorithm::Crc12Cdma2000 => Box::new(CrcChecksum::new(&CRC12_CDMA2000)),
+            Algorithm::Crc12Dect => Box::new(CrcChecksum::new(&CRC12_DECT)),
+            Algorithm::Crc12Gsm => Box::new(CrcChecksum::new(&CRC12_GSM)),
+            Algorithm::Crc12Umts => Box::new(CrcChecksum::new(&CRC12_UMTS)),
+            Algorithm::Crc13Bbc => Box::new(CrcChecksum::new(&CRC13_BBC)),
+            Algorithm::Crc14Darc => Box::new(CrcChecksum::new(&CRC14_DARC)),
+            Algorithm::Crc14Gsm => Box::new(CrcChecksum::new(&CRC14_GSM)),
+            Algorithm::Crc15Can => Box::new(CrcChecksum::new(&CRC15_CAN)),
+            Algorithm::Crc15Mpt1327 => Box::new(CrcChecksum::new(&CRC15_MPT1327)),
+            Algorithm::Crc16Arc => Box::new(CrcChecksum::new(&CRC16_ARC)),
+            Algorithm::Crc16Cdma2000 => Box::new(CrcChecksum::new(&CRC16_CDMA2000)),
+            Algorithm::Crc16Cms => Box::new(CrcChecksum::new(&CRC16_CMS)),
+            Algorithm::Crc16Dds110 => Box::new(CrcChecksum::new(&CRC16_DDS110)),
+            Algorithm::Crc16DectR => Box::new(CrcChecksum::new(&CRC16_DECT_R)),
+            Algorithm::Crc16DectX => Box::new(CrcChecksum::new(&CRC16_DECT_X)),
+            Algorithm::Crc16Dnp => Box::new(CrcChecksum::new(&CRC16_DNP)),
+            Algorithm::Crc16En13757 => Box::new(CrcChecksum::new(&CRC16_EN13757)),
+            Algorithm::Crc16Genibus => Box::new(CrcChecksum::new(&CRC16_GENIBUS)),
+            Algorithm::Crc16Gsm => Box::new(CrcChecksum::new(&CRC16_GSM)),
+            Algorithm::Crc16Ibm3740 => Box::new(CrcChecksum::new(&CRC16_IBM3740)),
+            Algorithm::Crc16IbmSdlc => Box::new(CrcChecksum::new(&CRC16_IBM_SDLC)),
+            Algorithm::Crc16IsoIec14443_3A =>
+                Box::new(CrcChecksum::new(&CRC16_ISO_IEC14443_3_A)),
+            Algorithm::Crc16Kermit => Box::new(CrcChecksum::new(&CRC16_KERMIT)),
+            Algorithm::Crc16Lj1200 => Box::new(CrcChecksum::new(&CRC16_LJ1200)),
+            Algorithm::Crc16M17 => Box::new(CrcChecksum::new(&CRC16_M17)),
+            Algorithm::Crc16MaximDow => Box::new(CrcChecksum::new(&CRC16_MAXIM_DOW)),
+            Algorithm::Crc16Mcrf4xx => Box::new(CrcChecksum::new(&CRC16_MCRF4XX)),
+            Algorithm::Crc16Modbus => Box::new(CrcChecksum::new(&CRC16_MODBUS)),
+            Algorithm::Crc16Nrsc5 => Box::new(CrcChecksum::new(&CRC16_NRSC5)),
+            Algorithm::Crc16OpensafetyA => Box::new(CrcChecksum::new(&CRC16_OPENSAFETY_A)),
+            Algorithm::Crc16OpensafetyB => Box::new(CrcChecksum::new(&CRC16_OPENSAFETY_B)),
+            Algorithm::Crc16Profibus => Box::new(CrcChecksum::new(&CRC16_PROFIBUS)),
+            Algorithm::Crc16Riello => Box::new(CrcChecksum::new(&CRC16_RIELLO)),
+            Algorithm::Crc16SpiFujitsu => Box::new(CrcChecksum::new(&CRC16_SPI_FUJITSU)),
+            Algorithm::Crc16T10Dif => Box::new(CrcChecksum::new(&CRC16_T10_DIF)),
+            Algorithm::Crc16Teledisk => Box::new(CrcChecksum::new(&CRC16_TELEDISK)),
+            Algorithm::Crc16Tms37157 => Box::new(CrcChecksum::new(&CRC16_TMS37157)),
+            Algorithm::Crc16Umts => Box::new(CrcChecksum::new(&CRC16_UMTS)),
+            Algorithm::Crc16Usb => Box::new(CrcChecksum::new(&CRC16_USB)),
+            Algorithm::Crc16Xmodem => Box::new(CrcChecksum::new(&CRC16_XMODEM)),
+            Algorithm::Crc17CanFd => Box::new(CrcChecksum::new(&CRC17_CAN_FD)),
+            Algorithm::Crc21CanFd => Box::new(CrcChecksum::new(&CRC21_CAN_FD)),
+            Algorithm::Crc24Ble => Box::new(CrcChecksum::new(&CRC24_BLE)),
+            Algorithm::Crc24FlexrayA => Box::new(CrcChecksum::new(&CRC24_FLEXRAY_A)),
+            Algorithm::Crc24FlexrayB => Box::new(CrcChecksum::new(&CRC24_FLEXRAY_B)),
+            Algorithm::Crc24Interlaken => Box::new(CrcChecksum::new(&CRC24_INTERLAKEN)),
+            Algorithm::Crc24LteA => Box::new(CrcChecksum::new(&CRC24_LTE_A)),
+            Algorithm::Crc24LteB => Box::new(CrcChecksum::new(&CRC24_LTE_B)),
+            Algorithm::Crc24Openpgp => Box::new(CrcChecksum::new(&CRC24_OPENPGP)),
+            Algorithm::Crc24Os9 => Box::new(CrcChecksum::new(&CRC24_OS9)),
+            Algorithm::Crc30Cdma => Box::new(CrcChecksum::new(&CRC30_CDMA)),
+            Algorithm::Crc31Philips => Box::new(CrcChecksum::new(&CRC31_PHILIPS)),
+            Algorithm::Crc32Aixm => Box::new(CrcChecksum::new(&CRC32_AIXM)),
+            Algorithm::Crc32Autosar => Box::new(CrcChecksum::new(&CRC32_AUTOSAR)),
+            Algorithm::Crc32Base91D => Box::new(CrcChecksum::new(&CRC32_BASE91_D)),
+            Algorithm::Crc32Bzip2 => Box::new(CrcChecksum::new(&CRC32_BZIP2)),
+            Algorithm::Crc32CdRomEdc => Box::new(CrcChecksum::new(&CRC32_CD_ROM_EDC)),
+            Algorithm::Crc32Cksum => Box::new(CrcChecksum::new(&CRC32_CKSUM)),
+            Algorithm::Crc32Iscsi => Box::new(CrcChecksum::new(&CRC32_ISCSI)),
+            Algorithm::Crc32Isohdlc => Box::new(CrcChecksum::new(&CRC32_ISOHDLC)),
+            Algorithm::Crc32Jamcrc => Box::new(CrcChecksum::new(&CRC32_JAMCRC)),
+            Algorithm::Crc32Mef => Box::new(CrcChecksum::new(&CRC32_MEF)),
+            Algorithm::Crc32Mpeg2 => Box::new(CrcChecksum::new(&CRC32_MPEG2)),
+            Algorithm::Crc32Xfer => Box::new(CrcChecksum::new(&CRC32_XFER)),
+            Algorithm::Crc40Gsm => Box::new(CrcChecksum::new(&CRC40_GSM)),
+            Algorithm::Crc64Ecma182 => Box::new(CrcChecksum::new(&CRC64_ECMA182)),
+            Algorithm::Crc64Goiso => Box::new(CrcChecksum::new(&CRC64_GOISO)),
+            Algorithm::Crc64Ms => Box::new(CrcChecksum::new(&CRC64_MS)),
+            Algorithm::Crc64Nvme => Box::new(CrcChecksum::new(&CRC64_NVME)),
+            Algorithm::Crc64Redis => Box::new(CrcChecksum::new(&CRC64_REDIS)),
+            Algorithm::Crc64We => Box::new(CrcChecksum::new(&CRC64_WE)),
+            Algorithm::Crc64Xz => Box::new(CrcChecksum::new(&CRC64_XZ)),
+            Algorithm::Crc82Darc => Box::new(CrcChecksum::new(&CRC82_DARC)),
+            Algorithm::Md5 => Box::new(Md5::new()),
+            Algorithm::Ripemd160 => Box::new(Hex(Ripemd160::new())),
+            Algorithm::Ripemd320 => Box::new(Hex(Ripemd320::new())),
+            Algorithm::Whirlpool => Box::new(Hex(Whirlpool::new())),
+            Algorithm::Tiger => Box::new(Hex(Tiger::new())),
+            Algorithm::Tiger2 => Box::new(Hex(Tiger2::new())),
+            Algorithm::Streebog256 => Box::new(Hex(Streebog256::new())),
+            Algorithm::Streebog512 => Box::new(Hex(Streebog512::new())),
+            Algorithm::Sm3 => Box::new(Hex(Sm3::new())),
+            Algorithm::Md4 => Box::new(Hex(Md4::new())),
+            Algorithm::Md2 => Box::new(Hex(Md2::new())),
+            Algorithm::Sure25 => Box::new(Sure25Checksum(Sure25::new(), false)),
+            Algorithm::Xxh32 => Box::new(Xxh32::new(seed as u32)),
+            Algorithm::Xxh64 => Box::new(Xxh64::new(seed)),
+            Algorithm::Xxh3_64 => Box::new(Xxh3Checksum(Xxh3::with_seed(seed), false)),
+            Algorithm::Xxh3_128 => Box::new(Xxh3Checksum(Xxh3::with_seed(seed), true)),
+            Algorithm::Fnv1a64 => Box::new(FnvHasher::with_key(seed)),
+            Algorithm::Murmur3_128 => Box::new(Murmur3::with_seed(seed as u32)),
+            Algorithm::Ssdeep => Box::new(Ssdeep::new()),
+            Algorithm::Tlsh => Box::new(Tlsh::new()),
+        }
+    }
+}
 
-        let crc = Crc::<u8>::new(&CRC_4_G_704);
-        let crc4_g704 = format!("{:02x}", crc.checksum(data.as_slice()));
+impl FromStr for Algorithm {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Algorithm, Error> {
+        let name = name.trim().to_lowercase().replace('-', "_");
+        Algorithm::every()
+            .find(|algorithm| {
+                algorithm.name() == name
+                    || algorithm.catalogue().is_some_and(|crc| crc.eq_ignore_ascii_case(&name))
+            })
+            .ok_or_else(|| Error::Error(format!("unknown algorithm: {:#?}", name)))
+    }
+}
+
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// the set of algorithms to compute over the data given to
+/// [`TableV1::cs_reader`], defaults to [`Algorithm::ALL`] computed
+/// on the calling thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Digester {
+    algorithms: Vec<Algorithm>,
+    threads: usize,
+    blake3: Blake3Mode,
+    seeds: BTreeMap<Algorithm, u64>,
+    piecewise: Option<(Algorithm, u64)>,
+    crcs: Vec<Rocksoft>,
+    range: Option<ByteRange>,
+    key: Option<Key>,
+    keyed: Vec<Algorithm>,
+    length: Option<usize>,
+    customization: String,
+}
+
+impl Default for Digester {
+    fn default() -> Digester {
+        Digester::new(Algorithm::ALL.to_vec())
+    }
+}
+
+impl Digester {
+    pub fn new(algorithms: Vec<Algorithm>) -> Digester {
+        Digester {
+            algorithms,
+            threads: 1,
+            blake3: Blake3Mode::Hash,
+            seeds: BTreeMap::new(),
+            piecewise: None,
+            crcs: Vec::new(),
+            range: None,
+            key: None,
+            keyed: Vec::new(),
+            length: None,
+            customization: String::new(),
+        }
+    }
+
+    /// seeds one of the non-cryptographic algorithms, see
+    /// [`Algorithm::default_seed`]
+    pub fn with_seed(mut self, algorithm: Algorithm, seed: u64) -> Result<Digester, Error> {
+        if algorithm.default_seed().is_none() {
+            return Err(Error::Error(format!("{} does not take a seed", algorithm)));
+        }
+        let narrow = matches!(algorithm, Algorithm::Xxh32 | Algorithm::Murmur3_128);
+        if narrow && seed > u32::MAX as u64 {
+            return Err(Error::Error(format!("{} takes a 32-bit seed: {}", algorithm, seed)));
+        }
+        self.seeds.insert(algorithm, seed);
+        Ok(self)
+    }
+
+    pub fn seed(&self, algorithm: Algorithm) -> u64 {
+        self.seeds
+            .get(&algorithm)
+            .copied()
+            .or(algorithm.default_seed())
+            .unwrap_or_default()
+    }
+
+    /// seeds of the selected algorithms which differ from their default
+    fn custom_seeds(&self) -> BTreeMap<String, u64> {
+        self.algorithms
+            .iter()
+            .filter(|algorithm| self.seeds.contains_key(algorithm))
+            .filter(|algorithm| Some(self.seed(**algorithm)) != algorithm.default_seed())
+            .map(|algorithm| (algorithm.to_string(), self.seed(*algorithm)))
+            .collect()
+    }
+
+    /// output length in bytes of every extendable-output function, see
+    /// [`Algorithm::default_length`]
+    pub fn with_xof_length(mut self, length: usize) -> Result<Digester, Error> {
+        if length == 0 {
+            return Err(Error::Error("the output length must not be 0".to_string()));
+        }
+        self.length = Some(length);
+        Ok(self)
+    }
+
+    pub fn xof_length(&self, algorithm: Algorithm) -> Option<usize> {
+        algorithm.default_length().map(|length| self.length.unwrap_or(length))
+    }
+
+    /// output lengths of the selected extendable-output functions
+    fn xof_lengths(&self) -> BTreeMap<String, usize> {
+        self.algorithms
+            .iter()
+            .filter_map(|algorithm| Some((algorithm.to_string(), self.xof_length(*algorithm)?)))
+            .collect()
+    }
+
+    /// customization string `S` of [`Algorithm::Cshake128`] and
+    /// [`Algorithm::Cshake256`], whose function name `N` is empty
+    pub fn with_customization(mut self, customization: impl std::fmt::Display) -> Digester {
+        self.customization = customization.to_string();
+        self
+    }
+
+    pub fn customization(&self) -> &str {
+        &self.customization
+    }
+
+    /// spreads the algorithms across `threads` threads which receive
+    /// every chunk read, `0` runs one thread per algorithm so that
+    /// the slowest algorithm bounds the time to digest the data.
+    pub fn with_threads(mut self, threads: usize) -> Digester {
+        self.threads = threads;
+        self
+    }
+
+    /// computes [`Algorithm::Blake3`] as a keyed hash (i.e.: MAC)
+    pub fn with_blake3_key(mut self, key: [u8; 32]) -> Digester {
+        self.blake3 = Blake3Mode::Keyed(key);
+        self
+    }
+
+    /// computes [`Algorithm::Blake3`] in key derivation mode with the
+    /// given application-specific `context` string
+    pub fn with_blake3_context(mut self, context: impl std::fmt::Display) -> Digester {
+        self.blake3 = Blake3Mode::DeriveKey(context.to_string());
+        self
+    }
+
+    /// additionally digests every `size` bytes of the data with the
+    /// given algorithm, see [`TableV1::piecewise`]
+    pub fn with_piecewise(mut self, algorithm: Algorithm, size: u64) -> Result<Digester, Error> {
+        if size == 0 {
+            return Err(Error::Error(format!("piecewise {}: size must not be 0", algorithm)));
+        }
+        self.piecewise = Some((algorithm, size));
+        Ok(self)
+    }
+
+    pub fn piecewise(&self) -> Option<(Algorithm, u64)> {
+        self.piecewise
+    }
+
+    /// digests only `length` bytes from `offset` onwards, or up to the
+    /// end of the data when `length` is [`None`], see [`TableV1::range`]
+    pub fn with_range(mut self, offset: u64, length: Option<u64>) -> Digester {
+        self.range = Some(ByteRange { offset, length });
+        self
+    }
+
+    pub fn range(&self) -> Option<ByteRange> {
+        self.range
+    }
+
+    pub fn algorithms(&self) -> &[Algorithm] {
+        &self.algorithms
+    }
+
+    pub fn blake3(&self) -> &Blake3Mode {
+        &self.blake3
+    }
+
+    /// computes a [`Rocksoft`] CRC besides the algorithms, named after
+    /// [`Rocksoft::name`] which must differ from the fields of [`TableV1`]
+    pub fn with_crc(mut self, crc: Rocksoft) -> Result<Digester, Error> {
+        crc.validate()?;
+        let reserved = crc.name.parse::<Algorithm>().is_ok() || FIELDS.contains(&crc.name.as_str());
+        if reserved || self.crcs.iter().any(|other| other.name == crc.name) {
+            return Err(Error::Error(format!("CRC {}: name already taken", crc.name)));
+        }
+        self.crcs.push(crc);
+        Ok(self)
+    }
+
+    pub fn crcs(&self) -> &[Rocksoft] {
+        &self.crcs
+    }
+
+    /// additionally computes the given algorithms keyed by `key`, i.e.:
+    /// HMAC or keyed BLAKE3, see [`Algorithm::KEYED`] and [`TableV1::keyed`]
+    pub fn with_key(mut self, key: Key, algorithms: &[Algorithm]) -> Result<Digester, Error> {
+        if let Some(algorithm) = algorithms.iter().find(|a| !Algorithm::KEYED.contains(a)) {
+            return Err(Error::Error(format!("{} cannot be keyed", algorithm)));
+        }
+        if algorithms.is_empty() {
+            return Err(Error::Error(format!("no algorithms to key with {}", key.id())));
+        }
+        self.key = Some(key);
+        self.keyed.clear();
+        for algorithm in algorithms {
+            if !self.keyed.contains(algorithm) {
+                self.keyed.push(*algorithm);
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn key(&self) -> Option<&Key> {
+        self.key.as_ref()
+    }
+
+    pub fn keyed(&self) -> &[Algorithm] {
+        &self.keyed
+    }
+
+    /// whether the hashers can be saved to a [`Checkpoint`], i.e.:
+    /// those of neither keyed nor piecewise digests of whole data by
+    /// the algorithms whose state can be exported, see
+    /// [`Algorithm::resumable`]
+    pub fn resumable(&self) -> Result<(), Error> {
+        if self.range.is_some() {
+            return Err(Error::Error("digests of a byte range cannot be checkpointed".to_string()));
+        }
+        if self.piecewise.is_some() {
+            return Err(Error::Error("piecewise digests cannot be checkpointed".to_string()));
+        }
+        if self.key.is_some() {
+            return Err(Error::Error("keyed digests cannot be checkpointed".to_string()));
+        }
+        let algorithms = self
+            .algorithms
+            .iter()
+            .filter(|algorithm| !algorithm.resumable())
+            .map(Algorithm::to_string)
+            .collect::<Vec<_>>();
+        if !algorithms.is_empty() {
+            return Err(Error::Error(format!(
+                "{} cannot be checkpointed, select those that can, e.g.: -a forensic",
+                algorithms.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn threads(&self) -> usize {
+        let checksums = self.algorithms.len() + self.crcs.len() + self.keyed.len();
+        if self.threads == 0 {
+            checksums
+        } else {
+            self.threads.min(checksums)
+        }
+    }
+
+    /// digest of `data` by a single algorithm, seeded and keyed as
+    /// the digests of [`TableV1::cs_reader`]
+    pub fn digest(&self, algorithm: Algorithm, data: &[u8]) -> String {
+        let mut checksum = algorithm.checksum(self);
+        checksum.update(data);
+        checksum.finalize()
+    }
+
+    fn pieces(&self) -> Option<Pieces> {
+        self.piecewise.map(|(algorithm, size)| Pieces {
+            algorithm,
+            size,
+            start: self.range.map(|range| range.offset).unwrap_or(0),
+            checksum: algorithm.checksum(self),
+            length: 0,
+            pieces: Vec::new(),
+        })
+    }
+
+    fn hashers(&self) -> Hashers {
+        self.spread(self.checksums(false))
+    }
+
+    /// hashers whose states can be saved to a [`Checkpoint`], see
+    /// [`Algorithm::resumable`]
+    fn resumable_hashers(&self) -> Hashers {
+        self.spread(self.checksums(true))
+    }
+
+    /// the hashers restored to the states of a [`Checkpoint`], which
+    /// must be of the very same algorithms and CRCs
+    fn resume(&self, checkpoint: &Checkpoint) -> Result<Hashers, Error> {
+        let mut checksums = self.checksums(true);
+        if checksums.len() != checkpoint.states.len() + checkpoint.crcs.len() {
+            let algorithms = checkpoint
+                .states
+                .keys()
+                .chain(checkpoint.crcs.keys())
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(Error::Error(format!("the checkpoint is of {}", algorithms)));
+        }
+        for (slot, checksum) in checksums.iter_mut() {
+            let state = match slot {
+                Slot::Field(algorithm) => checkpoint.states.get(&algorithm.to_string()),
+                Slot::Crc(name) => match checkpoint.crcs.get(name) {
+                    Some(saved) if self.crcs.iter().any(|crc| *crc == saved.crc) =>
+                        Some(&saved.state),
+                    Some(_) =>
+                        return Err(Error::Error(format!(
+                            "the checkpoint is of {} with other parameters",
+                            name
+                        ))),
+                    None => None,
+                },
+                Slot::Keyed(_) => None,
+            }
+            .ok_or_else(|| Error::Error(format!("the checkpoint has no state of {}", slot)))?;
+            checksum
+                .restore(&hex::decode(state)?)
+                .map_err(|e| Error::Error(format!("resuming {}: {}", slot, e)))?;
+        }
+        Ok(self.spread(checksums))
+    }
+
+    fn checksums(&self, resumable: bool) -> Vec<(Slot, Box<dyn Checksum>)> {
+        let checksum = |algorithm: &Algorithm| {
+            if resumable {
+                algorithm.resumable_checksum(self)
+            } else {
+                algorithm.checksum(self)
+            }
+        };
+        let mut checksums = self
+            .algorithms
+            .iter()
+            .map(|algorithm| (Slot::Field(*algorithm), checksum(algorithm)))
+            .collect::<Vec<_>>();
+        for crc in &self.crcs {
+            let checksum: Box<dyn Checksum> = Box::new(crc.digest());
+            checksums.push((Slot::Crc(crc.name.clone()), checksum));
+        }
+        if let Some(key) = &self.key {
+            for algorithm in &self.keyed {
+                if let Some(checksum) = algorithm.keyed_checksum(key) {
+                    checksums.push((Slot::Keyed(*algorithm), checksum));
+                }
+            }
+        }
+        checksums
+    }
+
+    /// hashers over the given checksums, as many threads as
+    /// [`Digester::threads`] sharing them
+    fn spread(&self, checksums: Vec<(Slot, Box<dyn Checksum>)>) -> Hashers {
+        let threads = self.threads();
+        if threads < 2 {
+            return Hashers::Inline(checksums);
+        }
+        let mut groups = (0..threads).map(|_| Vec::new()).collect::<Vec<_>>();
+        for (index, checksum) in checksums.into_iter().enumerate() {
+            groups[index % threads].push(checksum);
+        }
+        Hashers::Threaded(groups.into_iter().map(Worker::spawn).collect())
+    }
+}
+
+/// the modes of [`Algorithm::Blake3`]
+#[derive(Clone, PartialEq, Eq)]
+pub enum Blake3Mode {
+    Hash,
+    Keyed([u8; 32]),
+    DeriveKey(String),
+}
+
+impl Blake3Mode {
+    fn hasher(&self) -> Blake3 {
+        match self {
+            Blake3Mode::Hash => Blake3::new(),
+            Blake3Mode::Keyed(key) => Blake3::new_keyed(key),
+            Blake3Mode::DeriveKey(context) => Blake3::new_derive_key(context),
+        }
+    }
+}
+
+impl std::fmt::Debug for Blake3Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Blake3Mode::Hash => write!(f, "Hash"),
+            Blake3Mode::Keyed(_) => write!(f, "Keyed(..)"),
+            Blake3Mode::DeriveKey(context) => write!(f, "DeriveKey({:#?})", context),
+        }
+    }
+}
+
+/// where and how often [`TableV1::cs_resumable`] saves a [`Checkpoint`]
+struct Checkpoints<'a> {
+    path: &'a Path,
+    interval: u64,
+    /// canonical path and stat of the file being digested
+    file: (String, Stat),
+    crcs: &'a [Rocksoft],
+    /// offset of the next checkpoint
+    next: u64,
+}
+
+impl Checkpoints<'_> {
+    fn save(&mut self, hashers: &Hashers, offset: u64) -> Result<(), Error> {
+        if offset < self.next {
+            return Ok(());
+        }
+        let (states, crcs) = hashers.checkpoint(self.crcs)?;
+        let checkpoint = Checkpoint {
+            path: self.file.0.clone(),
+            stat: self.file.1.clone(),
+            offset,
+            states,
+            crcs,
+        };
+        checkpoint.write(self.path)?;
+        self.next = offset.saturating_add(self.interval);
+        Ok(())
+    }
+}
+
+/// running state of [`Digester::with_piecewise`], the last piece is
+/// shorter than `size` unless the data is a multiple of it
+struct Pieces {
+    algorithm: Algorithm,
+    size: u64,
+    /// offset of the first piece, i.e.: of the range of the data
+    start: u64,
+    checksum: Box<dyn Checksum>,
+    length: u64,
+    pieces: Vec<Piece>,
+}
+
+impl Pieces {
+    fn update(&mut self, mut chunk: &[u8], digester: &Digester) {
+        while !chunk.is_empty() {
+            let length = (self.size - self.length).min(chunk.len() as u64) as usize;
+            self.checksum.update(&chunk[..length]);
+            self.length += length as u64;
+            chunk = &chunk[length..];
+            if self.length == self.size {
+                self.push(digester);
+            }
+        }
+    }
+
+    fn push(&mut self, digester: &Digester) {
+        let checksum = std::mem::replace(&mut self.checksum, self.algorithm.checksum(digester));
+        let offset = self
+            .pieces
+            .last()
+            .map(|piece| piece.offset + piece.length)
+            .unwrap_or(self.start);
+        self.pieces.push(Piece {
+            offset,
+            length: self.length,
+            digest: checksum.finalize(),
+        });
+        self.length = 0;
+    }
+
+    fn finalize(mut self, digester: &Digester) -> Piecewise {
+        if self.length > 0 {
+            self.push(digester);
+        }
+        Piecewise {
+            algorithm: self.algorithm.to_string(),
+            size: self.size,
+            pieces: self.pieces,
+        }
+    }
+}
+
+/// where a digest is stored in a [`TableV1`]
+#[derive(Clone)]
+enum Slot {
+    Field(Algorithm),
+    Crc(String),
+    Keyed(Algorithm),
+}
+
+impl std::fmt::Display for Slot {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Slot::Field(algorithm) => write!(f, "{}", algorithm),
+            Slot::Crc(name) => write!(f, "{}", name),
+            Slot::Keyed(algorithm) => write!(f, "keyed {}", algorithm),
+        }
+    }
+}
+
+/// exported state of each checksum, see [`Checksum::state`]
+type States = Vec<(Slot, Option<Vec<u8>>)>;
+
+/// hex states by algorithm and those of the custom CRCs by name, as
+/// saved to a [`Checkpoint`]
+type Saved = (BTreeMap<String, String>, BTreeMap<String, CrcState>);
+
+/// running state of the selected hashers so that data can be
+/// digested one chunk at a time in a single pass.
+enum Hashers {
+    Inline(Vec<(Slot, Box<dyn Checksum>)>),
+    Threaded(Vec<Worker>),
+}
+
+impl Hashers {
+    fn update(&mut self, chunk: &[u8]) {
+        match self {
+            Hashers::Inline(checksums) =>
+                for (_, checksum) in checksums.iter_mut() {
+                    checksum.update(chunk);
+                },
+            Hashers::Threaded(workers) => {
+                let chunk = Arc::new(chunk.to_vec());
+                for worker in workers.iter() {
+                    worker.jobs.send(Job::Chunk(chunk.clone())).expect("digest worker hung up");
+                }
+            },
+        }
+    }
+
+    /// the states of every checksum after the chunks given so far,
+    /// failing for those that cannot be exported
+    fn checkpoint(&self, crcs: &[Rocksoft]) -> Result<Saved, Error> {
+        let states = match self {
+            Hashers::Inline(checksums) => checksums
+                .iter()
+                .map(|(slot, checksum)| (slot.clone(), checksum.state()))
+                .collect::<States>(),
+            Hashers::Threaded(workers) => workers.iter().flat_map(Worker::states).collect(),
+        };
+        let (mut fields, mut custom) = (BTreeMap::new(), BTreeMap::new());
+        for (slot, state) in states {
+            let state = state
+                .map(hex::encode)
+                .ok_or_else(|| Error::Error(format!("{} cannot be checkpointed", slot)))?;
+            match slot {
+                Slot::Field(algorithm) => {
+                    fields.insert(algorithm.to_string(), state);
+                },
+                Slot::Crc(name) => {
+                    let crc = crcs
+                        .iter()
+                        .find(|crc| crc.name == name)
+                        .cloned()
+                        .ok_or_else(|| Error::Error(format!("no parameters of {}", name)))?;
+                    custom.insert(name, CrcState { crc, state });
+                },
+                Slot::Keyed(algorithm) =>
+                    return Err(Error::Error(format!("keyed {} cannot be checkpointed", algorithm))),
+            };
+        }
+        Ok((fields, custom))
+    }
+
+    fn finalize(self, table: &mut TableV1) {
+        let digests = match self {
+            Hashers::Inline(checksums) => checksums
+                .into_iter()
+                .map(|(slot, checksum)| (slot, checksum.finalize()))
+                .collect::<Vec<_>>(),
+            Hashers::Threaded(workers) => workers.into_iter().flat_map(Worker::join).collect(),
+        };
+        for (slot, digest) in digests {
+            table.put(slot, digest);
+        }
+    }
+}
+
+/// what a [`Worker`] is sent
+enum Job {
+    Chunk(Arc<Vec<u8>>),
+    /// export the states once the chunks sent before are digested
+    States(Sender<States>),
+}
+
+/// thread updating a share of the checksums with every chunk it
+/// receives until its channel closes.
+struct Worker {
+    jobs: SyncSender<Job>,
+    handle: JoinHandle<Vec<(Slot, String)>>,
+}
+
+impl Worker {
+    fn spawn(mut checksums: Vec<(Slot, Box<dyn Checksum>)>) -> Worker {
+        let (jobs, receiver) = sync_channel::<Job>(4);
+        let handle = spawn(move || {
+            for job in receiver {
+                match job {
+                    Job::Chunk(chunk) =>
+                        for (_, checksum) in checksums.iter_mut() {
+                            checksum.update(&chunk);
+                        },
+                    Job::States(sender) => {
+                        let states = checksums
+                            .iter()
+                            .map(|(slot, checksum)| (slot.clone(), checksum.state()))
+                            .collect();
+                        sender.send(states).expect("digest worker hung up");
+                    },
+                }
+            }
+            checksums
+                .into_iter()
+                .map(|(slot, checksum)| (slot, checksum.finalize()))
+                .collect()
+        });
+        Worker { jobs, handle }
+    }
+
+    fn states(&self) -> States {
+        let (sender, receiver) = channel::<States>();
+        self.jobs.send(Job::States(sender)).expect("digest worker hung up");
+        receiver.recv().expect("digest worker hung up")
+    }
+
+    fn join(self) -> Vec<(Slot, String)> {
+        drop(self.jobs);
+        self.handle.join().expect("digest worker panicked")
+    }
+}
+
+trait Checksum: Send {
+    fn update(&mut self, chunk: &[u8]);
+    fn finalize(self: Box<Self>) -> String;
+
+    /// the state to save in a [`Checkpoint`], [`None`] for the
+    /// algorithms whose state cannot be exported
+    fn state(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn restore(&mut self, _state: &[u8]) -> Result<(), Error> {
+        Err(Error::Error("the state cannot be restored".to_string()))
+    }
+
+    /// bits of the digest, [`None`] for the fuzzy digests which are
+    /// rendered as text rather than bytes
+    fn bits(&self) -> Option<usize> {
+        None
+    }
+}
+
+struct Hex<D>(D);
+
+impl<D: Digest + Send> Checksum for Hex<D> {
+    fn update(&mut self, chunk: &[u8]) {
+        Digest::update(&mut self.0, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        hex::encode(self.0.finalize())
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(<D as Digest>::output_size() * 8)
+    }
+}
+
+/// an extendable-output function read for as many bytes as given
+struct Xof<H>(H, usize);
+
+impl<H: ExtendableOutput + Send> Checksum for Xof<H> {
+    fn update(&mut self, chunk: &[u8]) {
+        sha3::digest::Update::update(&mut self.0, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        let mut digest = vec![0u8; self.1];
+        self.0.finalize_xof().read(&mut digest);
+        hex::encode(digest)
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(self.1 * 8)
+    }
+}
+
+/// HMAC of any of the [`Digest`] implementations, e.g.: SHA-2
+struct Hmac<D: Digest + BlockSizeUser>(SimpleHmac<D>);
+
+impl<D: Digest + BlockSizeUser> Hmac<D> {
+    fn new(key: &[u8]) -> Hmac<D> {
+        Hmac(SimpleHmac::<D>::new_from_slice(key).expect("HMAC takes keys of any length"))
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Send> Checksum for Hmac<D> {
+    fn update(&mut self, chunk: &[u8]) {
+        Mac::update(&mut self.0, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        hex::encode(self.0.finalize().into_bytes())
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(<D as Digest>::output_size() * 8)
+    }
+}
+
+/// a CRC in progress along with its algorithm
+struct CrcChecksum<W: crc::Width>(&'static Crc<W>, crc::Digest<'static, W>);
+
+/// widths of which the `crc` crate makes a [`crc::Digest`]
+trait CrcWidth: crc::Width {
+    fn digest(crc: &'static Crc<Self>) -> crc::Digest<'static, Self>;
+}
+
+impl<W: CrcWidth> CrcChecksum<W> {
+    fn new(crc: &'static Crc<W>) -> CrcChecksum<W> {
+        CrcChecksum(crc, W::digest(crc))
+    }
+}
+
+/// the state of a CRC is the register as the initial value of its
+/// algorithm would be written, i.e.: neither reflected nor xored
+macro_rules! crc_checksum {
+    ($($width:ty),*) => {
+        $(impl CrcWidth for $width {
+            fn digest(crc: &'static Crc<$width>) -> crc::Digest<'static, $width> {
+                crc.digest()
+            }
+        }
+
+        impl Checksum for CrcChecksum<$width> {
+            fn update(&mut self, chunk: &[u8]) {
+                self.1.update(chunk);
+            }
+
+            fn finalize(self: Box<Self>) -> String {
+                let width = self.0.algorithm.width as usize;
+                RawDigest::from_value(None, self.1.finalize() as u128, width).hex()
+            }
+
+            fn bits(&self) -> Option<usize> {
+                Some(self.0.algorithm.width as usize)
+            }
+
+            fn state(&self) -> Option<Vec<u8>> {
+                let algorithm = self.0.algorithm;
+                let register = self.1.clone().finalize() ^ algorithm.xorout;
+                let register = if algorithm.refout {
+                    register.reverse_bits() >> (<$width>::BITS as u8 - algorithm.width)
+                } else {
+                    register
+                };
+                Some(register.to_be_bytes().to_vec())
+            }
+
+            fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+                let register = state.try_into().map(<$width>::from_be_bytes).map_err(|_| {
+                    Error::Error(format!("a CRC state of {} bytes", state.len()))
+                })?;
+                self.1 = self.0.digest_with_initial(register);
+                Ok(())
+            }
+        })*
+    };
+}
+crc_checksum!(u8, u16, u32, u64, u128);
+
+impl Checksum for RocksoftDigest {
+    fn update(&mut self, chunk: &[u8]) {
+        RocksoftDigest::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        self.hex()
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(self.width() as usize)
+    }
+
+    fn state(&self) -> Option<Vec<u8>> {
+        Some(self.register().to_be_bytes().to_vec())
+    }
+
+    fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+        let register = state
+            .try_into()
+            .map(u64::from_be_bytes)
+            .map_err(|_| Error::Error(format!("a CRC state of {} bytes", state.len())))?;
+        self.set_register(register);
+        Ok(())
+    }
+}
+
+impl Checksum for Md5 {
+    fn update(&mut self, chunk: &[u8]) {
+        self.consume(chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        format!("{:x}", self.compute())
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(128)
+    }
+}
+
+impl<C: Compress> Checksum for BlockDigest<C> {
+    fn update(&mut self, chunk: &[u8]) {
+        BlockDigest::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        hex::encode(BlockDigest::finalize(*self))
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(self.size() * 8)
+    }
+
+    fn state(&self) -> Option<Vec<u8>> {
+        Some(BlockDigest::state(self))
+    }
+
+    fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+        BlockDigest::restore(self, state)
+    }
+}
+
+impl Checksum for Sponge {
+    fn update(&mut self, chunk: &[u8]) {
+        Sponge::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        hex::encode(Sponge::finalize(*self))
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(self.size() * 8)
+    }
+
+    fn state(&self) -> Option<Vec<u8>> {
+        Some(Sponge::state(self))
+    }
+
+    fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+        Sponge::restore(self, state)
+    }
+}
+
+impl Checksum for Blake3 {
+    fn update(&mut self, chunk: &[u8]) {
+        Blake3::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        Blake3::finalize(&self).to_hex().to_string()
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(blake3::OUT_LEN * 8)
+    }
+}
+
+impl Checksum for Xxh32 {
+    fn update(&mut self, chunk: &[u8]) {
+        Xxh32::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        RawDigest::from_value(None, self.digest() as u128, 32).hex()
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(32)
+    }
+}
+
+impl Checksum for Xxh64 {
+    fn update(&mut self, chunk: &[u8]) {
+        Xxh64::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        RawDigest::from_value(None, self.digest() as u128, 64).hex()
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(64)
+    }
+}
+
+/// XXH3 in progress, rendered as `xxh3_128` when the flag is set
+struct Xxh3Checksum(Xxh3, bool);
+
+impl Checksum for Xxh3Checksum {
+    fn update(&mut self, chunk: &[u8]) {
+        self.0.update(chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        if self.1 {
+            RawDigest::from_value(None, self.0.digest128(), 128).hex()
+        } else {
+            RawDigest::from_value(None, self.0.digest() as u128, 64).hex()
+        }
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(if self.1 { 128 } else { 64 })
+    }
+}
+
+impl Checksum for FnvHasher {
+    fn update(&mut self, chunk: &[u8]) {
+        self.write(chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        RawDigest::from_value(None, self.finish() as u128, 64).hex()
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(64)
+    }
+
+    fn state(&self) -> Option<Vec<u8>> {
+        Some(self.finish().to_be_bytes().to_vec())
+    }
+
+    fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+        let state = state
+            .try_into()
+            .map(u64::from_be_bytes)
+            .map_err(|_| Error::Error(format!("an FNV state of {} bytes", state.len())))?;
+        *self = FnvHasher::with_key(state);
+        Ok(())
+    }
+}
+
+/// rendered as the 16 bytes written by the reference implementation,
+/// i.e.: `h1` then `h2` in little-endian
+impl Checksum for Murmur3 {
+    fn update(&mut self, chunk: &[u8]) {
+        self.write(chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        let (h1, h2) = self.finish128();
+        format!("{}{}", hex::encode(h1.to_le_bytes()), hex::encode(h2.to_le_bytes()))
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(128)
+    }
+}
+
+/// context-triggered piecewise hash in the format of `ssdeep`,
+/// e.g.: `3:aaX8v:aV`, data larger than [`Ssdeep::MAX_INPUT_SIZE`]
+/// cannot be hashed and renders empty as `ssdeep` prints no hash
+impl Checksum for Ssdeep {
+    fn update(&mut self, chunk: &[u8]) {
+        Ssdeep::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        Ssdeep::finalize(&self).map(|hash| hash.to_string()).unwrap_or_default()
+    }
+}
+
+/// TLSH with 128 buckets and a 1 byte checksum as printed by the
+/// reference `tlsh` tool, which prints `TNULL` for data too short or
+/// too uniform to be hashed
+impl Checksum for Tlsh {
+    fn update(&mut self, chunk: &[u8]) {
+        Tlsh::update(self, chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        match self.build() {
+            Some(tlsh) => String::from_utf8_lossy(&tlsh.hash()).to_string(),
+            None => "TNULL".to_string(),
+        }
+    }
+}
+
+impl Checksum for RollingAdler32 {
+    fn update(&mut self, chunk: &[u8]) {
+        self.update_buffer(chunk);
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        RawDigest::from_value(None, self.hash() as u128, 32).hex()
+    }
+
+    fn bits(&self) -> Option<usize> {
+        Some(32)
+    }
+
+    fn state(&self) -> Option<Vec<u8>> {
+        Some(self.hash().to_be_bytes().to_vec())
+    }
+
+    fn restore(&mut self, state: &[u8]) -> Result<(), Error> {
+        let state = state
+            .try_into()
+            .map(u32::from_be_bytes)
+            .map_err(|_| Error::Error(format!("an Adler-32 state of {} bytes", state.len())))?;
+        *self = RollingAdler32::from_value(state);
+        Ok(())
+    }
+}
+
+/// SURE25 only mixes the first bytes of each update and runs out of
+/// rounds after a few updates, so it is only fed the first chunk,
+/// which yields the same digest as hashing the whole data at once.
+struct Sure25Checksum(Sure25, bool);
+
+impl Checksum for Sure25Checksum {
+    fn update(&mut self, chunk: &[u8]) {
+        if !self.1 && !chunk.is_empty() {
+            self.0.update(chunk);
+            self.1 = true;
+        }
+    }
+
+    fn finalize(self: Box<Self>) -> String {
+        hex::encode(self.0.finalize())
+    }
 
-        let crc = Crc::<u8>::new(&CRC_6_GSM);
-        let crc6_gsm = format!("{:02x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u8>::new(&CRC_6_CDMA2000_A);
-        let crc6_cdma_2000a = format!("{:02x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u8>::new(&CRC_6_CDMA2000_B);
-        let crc6_cdma_2000b = format!("{:02x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u8>::new(&CRC_8_LTE);
-        let crc8_lte = format!("{:02x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u8>::new(&CRC_5_G_704);
-        let crc5_g704 = format!("{:02x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u16>::new(&CRC_16_TELEDISK);
-        let crc16_teledisk = format!("{:04x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u16>::new(&CRC_16_OPENSAFETY_A);
-        let crc16_opensafety_a = format!("{:04x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u16>::new(&CRC_16_OPENSAFETY_B);
-        let crc16_opensafety_b = format!("{:04x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u16>::new(&CRC_16_PROFIBUS);
-        let crc16_profibus = format!("{:04x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u16>::new(&CRC_16_USB);
-        let crc16_usb = format!("{:04x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u32>::new(&CRC_32_ISO_HDLC);
-        let crc32_isohdlc = format!("{:08x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u64>::new(&CRC_40_GSM);
-        let crc40_gsm = format!("{:016x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u64>::new(&CRC_64_MS);
-        let crc64_ms = format!("{:08x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u64>::new(&CRC_64_WE);
-        let crc64_we = format!("{:08x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u16>::new(&CRC_16_XMODEM);
-        let crc16_xmodem = format!("{:08x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u32>::new(&CRC_24_BLE);
-        let crc24_ble = format!("{:08x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u32>::new(&CRC_24_OPENPGP);
-        let crc24_openpgp = format!("{:08x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u64>::new(&CRC_64_REDIS);
-        let crc64_redis = format!("{:016x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u64>::new(&CRC_64_XZ);
-        let crc64_xz = format!("{:016x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u64>::new(&CRC_64_GO_ISO);
-        let crc64_goiso = format!("{:016x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u64>::new(&CRC_64_ECMA_182);
-        let crc64_ecma182 = format!("{:016x}", crc.checksum(data.as_slice()));
-
-        let crc = Crc::<u128>::new(&CRC_82_DARC);
-        let crc82_darc = format!("{:032x}", crc.checksum(data.as_slice()));
-
-        let sha1 = Sha1::default().digest(data.as_slice()).to_hex();
-
-        self.md5 = Some(md5);
-        self.sha1 = Some(sha1);
-        self.adler32 = Some(adler32);
-        self.crc3_gsm = Some(crc3_gsm);
-        self.crc4_g704 = Some(crc4_g704);
-        self.crc5_g704 = Some(crc5_g704);
-        self.crc6_gsm = Some(crc6_gsm);
-        self.crc6_cdma_2000a = Some(crc6_cdma_2000a);
-        self.crc6_cdma_2000b = Some(crc6_cdma_2000b);
-        self.crc8_lte = Some(crc8_lte);
-        self.crc11_flexray = Some(crc11_flexray);
-        self.crc16_teledisk = Some(crc16_teledisk);
-        self.crc16_opensafety_a = Some(crc16_opensafety_a);
-        self.crc16_opensafety_b = Some(crc16_opensafety_b);
-        self.crc16_profibus = Some(crc16_profibus);
-        self.crc16_usb = Some(crc16_usb);
-        self.crc16_xmodem = Some(crc16_xmodem);
-        self.crc24_ble = Some(crc24_ble);
-        self.crc24_openpgp = Some(crc24_openpgp);
-        self.crc32_bzip2 = Some(crc32_bzip2);
-        self.crc32_iscsi = Some(crc32_iscsi);
-        self.crc32_jamcrc = Some(crc32_jamcrc);
-        self.crc32_isohdlc = Some(crc32_isohdlc);
-        self.crc32_mpeg2 = Some(crc32_mpeg2);
-        self.crc40_gsm = Some(crc40_gsm);
-        self.crc64_xz = Some(crc64_xz);
-        self.crc64_redis = Some(crc64_redis);
-        self.crc64_goiso = Some(crc64_goiso);
-        self.crc64_ms = Some(crc64_ms);
-        self.crc64_we = Some(crc64_we);
-        self.crc64_ecma182 = Some(crc64_ecma182);
-        self.crc82_darc = Some(crc82_darc);
-        self.sha512_224 = Some(sha512_224);
-        self.sha512_256 = Some(sha512_256);
-        self.sha224 = Some(sha224);
-        self.sha256 = Some(sha256);
-        self.sha384 = Some(sha384);
-        self.sha512 = Some(sha512);
-        self.sha3_224 = Some(sha3_224);
-        self.sha3_256 = Some(sha3_256);
-        self.sha3_384 = Some(sha3_384);
-        self.sha3_512 = Some(sha3_512);
-        self.keccak224 = Some(keccak224);
-        self.keccak256 = Some(keccak256);
-        self.keccak256_full = Some(keccak256_full);
-        self.ripemd160 = Some(ripemd160);
-        self.ripemd320 = Some(ripemd320);
-        self.sure25 = Some(sure25);
-        return self.clone();
+    fn bits(&self) -> Option<usize> {
+        Some(self.0.finalize().len() * 8)
     }
 }
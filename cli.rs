@@ -1,15 +1,27 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::{Write, stdout};
+use std::sync::mpsc::{Receiver, Sender, channel, sync_channel};
+use std::sync::{Arc, Mutex};
+use std::thread::{available_parallelism, spawn};
 
-use carbon14::{Error, TableV1, clipboard_lines, stdin_lines};
-use clap::Parser;
-use iocore::{Error as IOCoreError, Path, WalkProgressHandler, walk_dir};
+use carbon14::similarity::{SSDEEP_THRESHOLD, TLSH_DISTANCE, similar};
+use carbon14::{
+    Algorithm, Checkpoint, Delimited, Digester, Encoding, Error, Key, Merkle, Rocksoft, Stat, Sums,
+    TableV1, clipboard_lines, manifest, relative_to_cwd, selftest, stdin_lines,
+};
+use carbon14::case::Bundle;
+use carbon14::dfxml::{self, Creator};
+use clap::{Parser, Subcommand, ValueEnum};
+use iocore::{Error as IOCoreError, OpenOptions, Path, WalkProgressHandler, walk_dir};
 use serde::Serialize;
-use serde_yaml;
 
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     targets: Vec<String>,
 
     #[clap(short = 'x', long)]
@@ -29,11 +41,354 @@ pub struct Cli {
 
     #[clap(short, long)]
     pub log_err: bool,
+
+    /// comma-separated algorithms and/or presets ("all", "fast", "forensic",
+    /// "fuzzy", "noncrypto", "xof", "legacy", "crc-all") to compute
+    #[clap(short, long, value_delimiter = ',', default_value = "all")]
+    pub algorithms: Vec<String>,
+
+    /// encoding of the digests of files: hex, base64, base32, nix32, sri
+    /// (e.g.: sha512-<base64>, base64 for algorithms other than sha256,
+    /// sha384 and sha512) or multihash (in multibase base32)
+    #[clap(short, long, default_value = "hex")]
+    pub encoding: String,
+
+    /// amount of files hashed concurrently, 0 uses every available core
+    #[clap(short, long, default_value_t = 1)]
+    pub jobs: usize,
+
+    /// seed of a non-cryptographic algorithm as ALGORITHM=SEED, e.g.: xxh64=0x2a,
+    /// defaults to 0 except for fnv1a_64 whose seed is its initial state
+    #[clap(long = "seed", value_name = "ALGORITHM=SEED")]
+    pub seeds: Vec<String>,
+
+    /// output length in bytes of shake128, shake256, cshake128 and cshake256,
+    /// defaults to 32 for the 128-bit ones and to 64 for the 256-bit ones
+    #[clap(long, value_name = "BYTES")]
+    pub xof_length: Option<usize>,
+
+    /// customization string of cshake128 and cshake256
+    #[clap(long, value_name = "STRING")]
+    pub customization: Option<String>,
+
+    /// custom CRC as NAME:width=W,poly=P[,init=I][,refin=B][,refout=B][,xorout=X][,check=C],
+    /// e.g.: modbus:width=16,poly=0x8005,init=0xffff,refin=true,check=0x4b37
+    #[clap(long = "crc", value_name = "NAME:PARAMETERS")]
+    pub crcs: Vec<String>,
+
+    /// YAML file listing custom CRCs with the same parameters as --crc
+    #[clap(long, value_name = "FILE")]
+    pub crc_file: Option<Path>,
+
+    /// threads digesting each file, every one running a share of the
+    /// algorithms, 0 runs one thread per algorithm
+    #[clap(long, default_value_t = 1)]
+    pub digest_threads: usize,
+
+    /// digest each file from OFFSET bytes onwards, e.g.: 512, 0x200 or 1M,
+    /// recorded in the range of its table
+    #[clap(long, visible_alias = "skip-header", value_name = "OFFSET", value_parser = parse_size)]
+    pub offset: Option<u64>,
+
+    /// digest only LENGTH bytes of each file, from --offset onwards
+    #[clap(long, value_name = "LENGTH", value_parser = parse_size)]
+    pub length: Option<u64>,
+
+    /// also digest every SIZE bytes of each file, e.g.: 4096, 64k, 1M or 2G
+    #[clap(long, value_name = "SIZE", value_parser = parse_size)]
+    pub piecewise: Option<u64>,
+
+    /// algorithm of the --piecewise digests
+    #[clap(long, default_value = "md5", requires = "piecewise")]
+    pub piecewise_algorithm: String,
+
+    /// also digest every scanned directory as a Merkle tree of the
+    /// ALGORITHM digests of its files, written after them in a summary
+    #[clap(long, value_name = "ALGORITHM")]
+    pub merkle: Option<String>,
+
+    /// comma-separated algorithms to also compute as HMAC, or keyed BLAKE3,
+    /// with the key of --key-file or --key-env, defaults to sha256
+    #[clap(long, value_delimiter = ',', value_name = "ALGORITHMS")]
+    pub keyed: Vec<String>,
+
+    /// file whose whole content, trailing newline included, is the key of --keyed
+    #[clap(long, value_name = "FILE", conflicts_with = "key_env")]
+    pub key_file: Option<Path>,
+
+    /// environment variable holding the key of --keyed
+    #[clap(long, value_name = "NAME")]
+    pub key_env: Option<String>,
+
+    /// identifier of the key written along with the keyed digests,
+    /// defaults to a fingerprint of the key
+    #[clap(long, value_name = "ID")]
+    pub key_id: Option<String>,
+
+    /// save the progress of digesting the one target file to FILE every
+    /// --checkpoint-interval bytes, removing it once done, see --resume.
+    /// Only MD5, SHA-1, SHA-2, SHA-3, Keccak, SHAKE, Adler-32, FNV and
+    /// the CRCs can be checkpointed, unlike some of the defaults, so
+    /// select them, e.g.: -a forensic
+    #[clap(long, value_name = "FILE")]
+    pub checkpoint: Option<Path>,
+
+    /// bytes digested between checkpoints, e.g.: 512M or 4G
+    #[clap(long, value_name = "SIZE", value_parser = parse_size, default_value = "1G")]
+    pub checkpoint_interval: u64,
+
+    /// continue from the --checkpoint of an interrupted run, if any
+    #[clap(long, requires = "checkpoint")]
+    pub resume: bool,
+
+    /// digest a file again up to N times when it changes while being read,
+    /// after which its table is marked unstable along with both of its stats
+    #[clap(long, value_name = "N", default_value_t = 2)]
+    pub retries: usize,
+
+    /// exit with an error once done when any file kept changing while being read
+    #[clap(long)]
+    pub fail_unstable: bool,
+
+    /// order in which the tables of scanned files are written
+    #[clap(long, value_enum, default_value_t = Order::Sorted)]
+    pub order: Order,
+
+    /// format of the output
+    #[clap(long, value_enum, default_value_t = Format::Yaml)]
+    pub format: Format,
+
+    /// algorithm of the checksum lists of --format gnu and bsd, defaults
+    /// to the one of --algorithms when only one is selected
+    #[clap(long, value_name = "ALGORITHM")]
+    pub list_algorithm: Option<String>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// lists the pairs of entries across one or more .c14 manifests
+    /// whose ssdeep or TLSH digests are similar, e.g.: of `-a fuzzy`
+    Similar {
+        #[clap(required = true)]
+        manifests: Vec<Path>,
+
+        /// minimum ssdeep score, from 0 to 100, of similar entries
+        #[clap(short, long, default_value_t = SSDEEP_THRESHOLD)]
+        threshold: u32,
+
+        /// maximum TLSH distance of similar entries, 0 meaning identical
+        #[clap(long, default_value_t = TLSH_DISTANCE)]
+        distance: i32,
+
+        /// accept manifests of older versions which padded some digests
+        /// to the width of another algorithm, e.g.: crc16_xmodem in 8 hex
+        /// digits rather than 4
+        #[clap(long)]
+        legacy_padding: bool,
+    },
+    /// digests the published known-answer vectors of every algorithm
+    /// and fails unless all of them match
+    Selftest,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// sorted by path regardless of which file finished hashing first
+    Sorted,
+    /// as soon as each file finishes hashing
+    Completion,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// YAML documents, each one terminated by `#\t∎`
+    #[default]
+    Yaml,
+    /// one JSON array holding every document
+    Json,
+    /// JSON Lines, i.e.: one JSON document per line flushed as it is written
+    Jsonl,
+    /// comma-separated values under a header row, see `TableV1::columns`
+    /// for the order of the columns
+    Csv,
+    /// tab-separated values under a header row, in the order of --format csv
+    Tsv,
+    /// checksum list of --list-algorithm as written by sha256sum, md5sum
+    /// or b2sum, i.e.: `<digest>  <filename>`
+    Gnu,
+    /// checksum list of --list-algorithm as written by sha256sum --tag,
+    /// i.e.: `SHA256 (<filename>) = <digest>`
+    Bsd,
+    /// Digital Forensics XML, i.e.: a `<fileobject>` of each file after
+    /// the `<creator>` of the run
+    Dfxml,
+    /// CASE/UCO JSON-LD, i.e.: an `observable:File` of each file along
+    /// with the `InvestigativeAction` of the run
+    Case,
+}
+
+impl Format {
+    pub fn delimited(&self) -> Option<Delimited> {
+        match self {
+            Format::Csv => Some(Delimited::Csv),
+            Format::Tsv => Some(Delimited::Tsv),
+            _ => None,
+        }
+    }
+
+    pub fn sums(&self) -> Option<Sums> {
+        match self {
+            Format::Gnu => Some(Sums::Gnu),
+            Format::Bsd => Some(Sums::Bsd),
+            _ => None,
+        }
+    }
+
+    /// whether any document can be written, rather than only tables
+    pub fn documents(&self) -> bool {
+        matches!(self, Format::Yaml | Format::Json | Format::Jsonl)
+    }
 }
 
 impl Cli {
     pub fn writer(&mut self) -> FWriter {
-        FWriter::new(self.path(), self.defer_write, self.log_err)
+        FWriter::new(self.path(), self.defer_write, self.log_err, self.format)
+    }
+
+    pub fn jobs(&self) -> usize {
+        if self.jobs == 0 {
+            available_parallelism().map(|jobs| jobs.get()).unwrap_or(1)
+        } else {
+            self.jobs
+        }
+    }
+
+    pub fn digester(&self) -> Result<Digester, Error> {
+        let mut algorithms = Algorithm::select(&self.algorithms)?;
+        if let Some(merkle) = self.merkle()? {
+            if !algorithms.contains(&merkle) {
+                algorithms.push(merkle);
+            }
+        }
+        let mut digester = Digester::new(algorithms).with_threads(self.digest_threads);
+        for seed in &self.seeds {
+            let (algorithm, seed) = seed.split_once('=').ok_or_else(|| {
+                Error::Error(format!("--seed {:#?}: expected ALGORITHM=SEED", seed))
+            })?;
+            let seed = match seed.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => seed.parse::<u64>(),
+            }
+            .map_err(|e| Error::Error(format!("--seed {}={}: {}", algorithm, seed, e)))?;
+            digester = digester.with_seed(algorithm.parse()?, seed)?;
+        }
+        if let Some(length) = self.xof_length {
+            digester = digester.with_xof_length(length)?;
+        }
+        if let Some(customization) = &self.customization {
+            let cshake = [Algorithm::Cshake128, Algorithm::Cshake256];
+            if !digester.algorithms().iter().any(|algorithm| cshake.contains(algorithm)) {
+                return Err(Error::Error(
+                    "--customization requires either cshake128 or cshake256".to_string(),
+                ));
+            }
+            digester = digester.with_customization(customization);
+        }
+        for crc in &self.crcs {
+            digester = digester.with_crc(crc.parse()?)?;
+        }
+        if let Some(path) = &self.crc_file {
+            for crc in Rocksoft::load(path)? {
+                digester = digester.with_crc(crc)?;
+            }
+        }
+        if self.offset.is_some() || self.length.is_some() {
+            digester = digester.with_range(self.offset.unwrap_or_default(), self.length);
+        }
+        if let Some(size) = self.piecewise {
+            digester = digester.with_piecewise(self.piecewise_algorithm.parse()?, size)?;
+        }
+        if let Some(key) = self.key()? {
+            let keyed = if self.keyed.is_empty() {
+                vec![Algorithm::Sha256]
+            } else {
+                Algorithm::select(&self.keyed)?
+            };
+            digester = digester.with_key(key, &keyed)?;
+        } else if !self.keyed.is_empty() || self.key_id.is_some() {
+            return Err(Error::Error(
+                "--keyed requires either --key-file or --key-env".to_string(),
+            ));
+        }
+        Ok(digester)
+    }
+
+    /// the key of --keyed, which is never given in the command line so
+    /// that it does not end up in the history of the shell
+    pub fn key(&self) -> Result<Option<Key>, Error> {
+        match (&self.key_file, &self.key_env) {
+            (Some(path), _) => Ok(Some(Key::from_file(path, self.key_id.clone())?)),
+            (None, Some(name)) => Ok(Some(Key::from_env(name, self.key_id.clone())?)),
+            (None, None) => Ok(None),
+        }
+    }
+
+    pub fn encoding(&self) -> Result<Encoding, Error> {
+        self.encoding.parse()
+    }
+
+    /// the algorithm of --format gnu and bsd, [`None`] for other formats
+    pub fn list_algorithm(&self, digester: &Digester) -> Result<Option<Algorithm>, Error> {
+        let Some(sums) = self.format.sums() else { return Ok(None) };
+        let algorithm = match (&self.list_algorithm, digester.algorithms()) {
+            (Some(algorithm), _) => algorithm.parse::<Algorithm>()?,
+            (None, [algorithm]) => *algorithm,
+            (None, _) =>
+                return Err(Error::Error(
+                    "checksum lists take one algorithm, pick it with --list-algorithm".to_string(),
+                )),
+        };
+        if !digester.algorithms().contains(&algorithm) {
+            return Err(Error::Error(format!(
+                "--list-algorithm {} is not one of --algorithms",
+                algorithm
+            )));
+        }
+        if sums == Sums::Bsd && algorithm.tag().is_none() {
+            return Err(Error::Error(format!(
+                "{} has no tag of BSD-style checksum lists, try --format gnu",
+                algorithm
+            )));
+        }
+        if self.encoding()? != Encoding::Hex || self.hexonly {
+            return Err(Error::Error(
+                "checksum lists take hex digests along with filenames".to_string(),
+            ));
+        }
+        Ok(Some(algorithm))
+    }
+
+    /// refuses --format case along with the options whose tables it
+    /// cannot write, i.e.: digests other than hex and byte ranges
+    pub fn case(&self) -> Result<(), Error> {
+        if self.format != Format::Case {
+            return Ok(());
+        }
+        if self.encoding()? != Encoding::Hex {
+            return Err(Error::Error(
+                "CASE hashes take hex digests, try --encoding hex".to_string(),
+            ));
+        }
+        if self.offset.is_some() || self.length.is_some() {
+            return Err(Error::Error(
+                "CASE content hashes are of whole files, not of --offset or --length".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn merkle(&self) -> Result<Option<Algorithm>, Error> {
+        self.merkle.as_ref().map(|merkle| merkle.parse()).transpose()
     }
 
     pub fn path(&self) -> Option<Path> {
@@ -46,7 +401,7 @@ impl Cli {
                         if path.is_file() {
                             let extension = match path.extension() {
                                 Some(extension) => format!("{}.c14", extension),
-                                None => format!(".c14"),
+                                None => ".c14".to_string(),
                             };
                             Some(Path::raw(path.with_extension(extension).name()))
                         } else {
@@ -66,17 +421,17 @@ impl Cli {
             .targets
             .iter()
             .filter(|s| !s.is_empty())
-            .map(|s| Path::raw(s))
+            .map(Path::raw)
             .filter(|p| p.exists())
             .map(|p| p.to_string())
             .collect();
-        let objects = if targets.len() > 0 {
+        let objects = if !targets.is_empty() {
             targets
         } else {
-            stdin_lines().or(clipboard_lines()).unwrap_or(Vec::new())
+            stdin_lines().or(clipboard_lines()).unwrap_or_default()
         };
         if objects.is_empty() {
-            Err(Error::Error(format!("no targets, try --help")))
+            Err(Error::Error("no targets, try --help".to_string()))
         } else {
             Ok(objects)
         }
@@ -84,61 +439,310 @@ impl Cli {
 }
 struct Carbon14 {
     pub cli: Cli,
+    /// files which kept changing while they were digested
+    pub unstable: Vec<String>,
 }
 impl Carbon14 {
     pub fn new() -> Carbon14 {
         let cli = Cli::parse();
-        Carbon14 { cli }
+        Carbon14 {
+            cli,
+            unstable: Vec::new(),
+        }
     }
 
     pub fn scan(&mut self) -> Result<FWriter, Error> {
-        let mut writer = self.cli.writer();
+        if let Some(checkpoint) = self.cli.checkpoint.clone() {
+            return self.resumable(&checkpoint);
+        }
+        let digester = self.cli.digester()?;
+        let mut writer = self
+            .cli
+            .writer()
+            .with_columns(TableV1::columns(&digester))
+            .with_algorithm(self.cli.list_algorithm(&digester)?);
+        self.cli.case()?;
+        let encoding = self.cli.encoding()?;
+        let merkle = self.cli.merkle()?;
+        if merkle.is_some() && !self.cli.format.documents() {
+            return Err(Error::Error(
+                "--merkle summaries can only be written as --format yaml, json or jsonl"
+                    .to_string(),
+            ));
+        }
+        let pool = Pool::new(self.cli.jobs(), self.cli.hexonly, self.cli.retries, &digester);
+        let mut targets = Vec::<Path>::new();
         for target in self.cli.objects()? {
             let target = Path::raw(&target);
             if target.exists() {
-                let target = target.canonicalize()?.relative_to_cwd();
-                if target.is_file() {
-                    match target.read_bytes() {
-                        Ok(bytes) => {
-                            let meta = (!self.cli.hexonly)
-                                .then_some(Some(target.to_string()))
-                                .unwrap_or(None);
-
-                            let table = TableV1::new(meta.clone()).cs(bytes);
-                            writer.append(&table).and(Ok(())).unwrap_or(());
-                        },
-                        Err(e) => {
-                            eprintln!("error reading {}: {}", &target, e);
-                        },
-                    }
-                } else if target.is_dir() {
-                    walk_dir(
-                        &target,
-                        Table {
-                            opt: self.cli.clone(),
-                        },
-                        None,
-                    )?;
-                } else {
-                    continue;
-                }
+                targets.push(relative_to_cwd(&target));
             } else {
                 let target = target.to_string();
                 let meta = Some(target.clone());
-                let table = TableV1::new(meta).cs(target.as_bytes().to_vec());
-                writer.append(&table)?;
+                let table = TableV1::new(meta).cs_reader(target.as_bytes(), &digester)?;
+                writer.append(&table.encoded(encoding)?)?;
+            }
+        }
+        // targets are walked while the tables of the files found so far
+        // are written, so that nothing waits for the whole traversal
+        let queue = pool.queue();
+        let traversal = spawn(move || -> Result<Roots, Error> {
+            let mut roots = Vec::new();
+            for target in targets {
+                if target.is_file() {
+                    queue
+                        .send(target)
+                        .map_err(|e| Error::Error(format!("queueing a file: {}", e)))?;
+                } else if target.is_dir() {
+                    let table = Table::new(queue.clone());
+                    walk_dir(&target, table.clone(), None)?;
+                    roots.push((target, table.files(), table.directories()));
+                }
+            }
+            Ok(roots)
+        });
+        let mut digests = BTreeMap::<String, Option<String>>::new();
+        for (path, result) in pool.finish(self.cli.order) {
+            match result {
+                Ok(table) => {
+                    if table.unstable() {
+                        eprintln!("[warning] {} changed while it was digested", &path);
+                        self.unstable.push(path.to_string());
+                    }
+                    writer.append(&table.encoded(encoding)?).and(Ok(())).unwrap_or(());
+                    if let Some(merkle) = merkle {
+                        digests.insert(path.to_string(), table.digest(merkle).map(String::from));
+                    }
+                },
+                Err(e) => {
+                    eprintln!("error reading {}: {}", &path, e);
+                    digests.insert(path.to_string(), None);
+                },
+            }
+        }
+        let roots = traversal
+            .join()
+            .map_err(|_| Error::Error("walking the targets: traversal panicked".to_string()))??;
+        if let Some(merkle) = merkle {
+            for (root, files, directories) in roots {
+                match tree(&root, &files, &directories, &digests) {
+                    Ok(tree) => {
+                        writer.append(tree.summary(&root.to_string(), merkle, &digester))?;
+                    },
+                    Err(e) => {
+                        eprintln!("error digesting {} as a merkle tree: {}", &root, e);
+                    },
+                }
             }
         }
         Ok(writer)
     }
 
+    /// digests the one target file saving checkpoints along the way,
+    /// resuming from the last one when asked to
+    pub fn resumable(&mut self, checkpoint: &Path) -> Result<FWriter, Error> {
+        let digester = self.cli.digester()?;
+        let mut writer = self
+            .cli
+            .writer()
+            .with_columns(TableV1::columns(&digester))
+            .with_algorithm(self.cli.list_algorithm(&digester)?);
+        self.cli.case()?;
+        let encoding = self.cli.encoding()?;
+        let targets = self.cli.objects()?;
+        let target = match targets.as_slice() {
+            [target] if Path::raw(target).is_file() => Path::raw(target),
+            _ => return Err(Error::Error("--checkpoint takes exactly one file".to_string())),
+        };
+        digester.resumable()?;
+        let resumed = match (checkpoint.exists(), self.cli.resume) {
+            (true, true) => Some(Checkpoint::read(checkpoint)?),
+            (true, false) =>
+                return Err(Error::Error(format!(
+                    "checkpoint {} exists, pass --resume to continue from it",
+                    checkpoint
+                ))),
+            (false, true) => {
+                eprintln!("no checkpoint at {}, digesting {} from the start", checkpoint, target);
+                None
+            },
+            (false, false) => None,
+        };
+        let meta = if self.cli.hexonly { None } else { Some(target.to_string()) };
+        let before = Stat::of(&target)?;
+        let file = target.open(OpenOptions::new().read(true))?;
+        let mut table = TableV1::new(meta).cs_resumable(
+            file,
+            &digester,
+            &target,
+            checkpoint,
+            self.cli.checkpoint_interval,
+            resumed,
+        )?;
+        let after = Stat::of(&target)?;
+        if before != after {
+            eprintln!("[warning] {} changed while it was digested", &target);
+            self.unstable.push(target.to_string());
+            table.mark_unstable(before, after);
+        }
+        writer.append(&table.encoded(encoding)?)?;
+        Ok(writer)
+    }
+
+    pub fn similar(
+        &mut self,
+        manifests: &[Path],
+        threshold: u32,
+        distance: i32,
+        legacy_padding: bool,
+    ) -> Result<FWriter, Error> {
+        let mut writer = self.cli.writer();
+        let mut entries = Vec::<(String, TableV1)>::new();
+        for path in manifests {
+            for (index, table) in manifest::read(path, legacy_padding)?.into_iter().enumerate() {
+                let label = match table.filename().or(table.data()) {
+                    Some(name) if manifests.len() > 1 => format!("{}:{}", path, name),
+                    Some(name) => name.to_string(),
+                    None => format!("{}[{}]", path, index),
+                };
+                entries.push((label, table));
+            }
+        }
+        for similarity in similar(&entries, threshold, distance) {
+            writer.append(&similarity)?;
+        }
+        Ok(writer)
+    }
+
+    /// writes the outcome of every known-answer test followed by the
+    /// [`selftest::Summary`], which is also returned, except in rows
+    /// of --format csv and tsv whose columns are those of the outcomes
+    pub fn selftest(&mut self) -> Result<(FWriter, selftest::Summary), Error> {
+        let mut writer = self.cli.writer();
+        let outcomes = selftest::run();
+        for outcome in &outcomes {
+            writer.append(outcome)?;
+        }
+        let summary = selftest::Summary::new(&outcomes);
+        if self.cli.format.delimited().is_none() {
+            writer.append(&summary)?;
+        }
+        Ok((writer, summary))
+    }
+
     pub fn launch() -> Result<(), Error> {
         let mut c14 = Carbon14::new();
-        let mut writer = c14.scan()?;
+        let (mut writer, summary) = match c14.cli.command.clone() {
+            Some(Command::Similar {
+                manifests,
+                threshold,
+                distance,
+                legacy_padding,
+            }) => (c14.similar(&manifests, threshold, distance, legacy_padding)?, None),
+            Some(Command::Selftest) => {
+                let (writer, summary) = c14.selftest()?;
+                (writer, Some(summary))
+            },
+            None => (c14.scan()?, None),
+        };
         writer.finish()?;
+        if c14.cli.fail_unstable && !c14.unstable.is_empty() {
+            return Err(Error::Error(format!(
+                "{} changed while being digested",
+                c14.unstable.join(", ")
+            )));
+        }
+        if let Some(summary) = summary.filter(|summary| summary.failed > 0) {
+            return Err(Error::Error(format!(
+                "{} of {} known-answer tests failed",
+                summary.failed,
+                summary.passed + summary.failed
+            )));
+        }
         Ok(())
     }
 }
+/// builds the [`Merkle`] tree of a directory out of the digests of the
+/// files found within it, failing when any of them could not be read
+pub fn tree(
+    root: &Path,
+    files: &[String],
+    directories: &[String],
+    digests: &BTreeMap<String, Option<String>>,
+) -> Result<Merkle, Error> {
+    // paths are compared once canonical as the root may be spelled
+    // otherwise than the paths found within it, e.g.: `.`
+    let base = root.try_canonicalize().to_path_buf();
+    let relative = |path: &str| {
+        Path::raw(path)
+            .try_canonicalize()
+            .to_path_buf()
+            .strip_prefix(&base)
+            .map(|relative| relative.to_string_lossy().to_string())
+            .map_err(|_| Error::Error(format!("{} was found outside of {}", path, root)))
+    };
+    let mut tree = Merkle::new();
+    for directory in directories {
+        tree.insert_directory(&relative(directory)?);
+    }
+    for file in files {
+        let digest = digests
+            .get(file)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| Error::Error(format!("{} could not be digested", file)))?;
+        tree.insert_file(&relative(file)?, &digest);
+    }
+    Ok(tree)
+}
+
+/// digests a file, again up to `retries` times while its [`Stat`]
+/// changes in the meantime, after which its table is marked unstable
+pub fn hash_file(
+    path: &Path,
+    hexonly: bool,
+    retries: usize,
+    digester: &Digester,
+) -> Result<TableV1, Error> {
+    let meta = if hexonly { None } else { Some(path.to_string()) };
+    let mut attempts = 0;
+    loop {
+        let before = Stat::of(path)?;
+        let file = path.open(OpenOptions::new().read(true))?;
+        let mut table = TableV1::new(meta.clone()).cs_seeker(file, digester)?;
+        let after = Stat::of(path)?;
+        if before == after {
+            return Ok(table);
+        }
+        if attempts == retries {
+            table.mark_unstable(before, after);
+            return Ok(table);
+        }
+        attempts += 1;
+    }
+}
+
+/// parses an amount of bytes with an optional binary unit, e.g.:
+/// `512`, `0x200`, `64k`, `1M`, `2GiB`
+pub fn parse_size(size: &str) -> Result<u64, String> {
+    let lower = size.trim().to_lowercase();
+    let (digits, unit) = [("k", 1u64 << 10), ("m", 1 << 20), ("g", 1 << 30), ("t", 1 << 40)]
+        .into_iter()
+        .flat_map(|(prefix, unit)| {
+            ["", "b", "ib"].map(|suffix| (format!("{}{}", prefix, suffix), unit))
+        })
+        .find_map(|(suffix, unit)| lower.strip_suffix(&suffix).map(|digits| (digits, unit)))
+        .unwrap_or((lower.as_str(), 1));
+    let amount = match digits.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => digits.parse::<u64>(),
+    }
+    .map_err(|e| format!("invalid size {:#?}: {}", size, e))?;
+    amount
+        .checked_mul(unit)
+        .ok_or_else(|| format!("invalid size {:#?}: too large", size))
+}
+
 pub fn main() {
     if let Err(des) = Carbon14::launch() {
         eprintln!("{}", des);
@@ -151,7 +755,19 @@ pub struct FWriter {
     path: Option<Path>,
     defer_write: bool,
     log_err: bool,
+    format: Format,
+    /// columns of the rows of --format csv and tsv, which default to
+    /// the keys of the first document
+    columns: Vec<String>,
+    /// algorithm of the checksum lists of --format gnu and bsd
+    algorithm: Option<Algorithm>,
+    /// creator of the document of --format dfxml
+    creator: Option<Creator>,
+    /// bundle of the document of --format case
+    bundle: Option<Bundle>,
     buffer: Vec<u8>,
+    /// documents appended so far
+    count: usize,
     pub standard: bool,
 }
 
@@ -161,63 +777,176 @@ impl Default for FWriter {
             path: None,
             defer_write: true,
             log_err: true,
+            format: Format::Yaml,
+            columns: Vec::new(),
+            algorithm: None,
+            creator: None,
+            bundle: None,
             buffer: Vec::new(),
+            count: 0,
             standard: true,
         }
     }
 }
 
 impl FWriter {
-    pub fn new(path: Option<Path>, defer_write: bool, log_err: bool) -> FWriter {
+    pub fn new(path: Option<Path>, defer_write: bool, log_err: bool, format: Format) -> FWriter {
         FWriter {
             path,
             defer_write,
             log_err,
+            format,
+            columns: Vec::new(),
+            algorithm: None,
+            creator: (format == Format::Dfxml).then(Creator::current),
+            bundle: (format == Format::Case).then(|| Bundle::new(Creator::current())),
             buffer: Vec::new(),
+            count: 0,
             standard: false,
         }
     }
 
+    pub fn with_columns(mut self, columns: Vec<String>) -> FWriter {
+        self.columns = columns;
+        self
+    }
+
+    pub fn with_algorithm(mut self, algorithm: Option<Algorithm>) -> FWriter {
+        self.algorithm = algorithm;
+        self
+    }
+
     pub fn append(&mut self, data: impl Serialize) -> Result<FWriter, Error> {
+        if self.format.delimited().is_some() && self.columns.is_empty() {
+            self.columns = Delimited::keys(&data)?;
+        }
         match self.encode(data) {
-            Ok(bytes) =>
+            Ok(bytes) => {
+                self.count += 1;
                 if self.defer_write {
                     self.buffer.extend_from_slice(&bytes);
-                } else {
-                    if let Err(y) = self
-                        .path
-                        .clone()
-                        .map(|path| {
-                            if path.exists() {
-                                path.append(&bytes)
-                            } else {
-                                path.write(&bytes)
-                                    .map(|path| path.size().unwrap().as_u64() as usize)
-                            }
-                        })
-                        .unwrap_or_else(|| stdout().write(&bytes).map_err(|e| IOCoreError::from(e)))
-                    {
-                        self.handle(y)?;
-                    }
-                },
+                } else if let Err(y) = self.write(&bytes) {
+                    self.handle(y)?;
+                }
+            },
             Err(y) => self.handle(y)?,
         }
         Ok(self.clone())
     }
 
+    /// writes to the output file, appending to it when it exists, or
+    /// else to stdout which is flushed right away
+    fn write(&self, bytes: &[u8]) -> Result<(), IOCoreError> {
+        match self.path.clone() {
+            Some(path) if path.exists() => path.append(bytes).map(|_| ()),
+            Some(path) => path.write(bytes).map(|_| ()),
+            None => {
+                let mut stdout = stdout().lock();
+                stdout.write_all(bytes).and_then(|_| stdout.flush()).map_err(IOCoreError::from)
+            },
+        }
+    }
+
+    /// a document in the [`Format`] of the writer, preceded by the
+    /// separator from the previous one when there is one
     pub fn encode(&self, data: impl Serialize) -> Result<Vec<u8>, Error> {
-        let mut bytes = b"\n---\n".to_vec();
-        let y = serde_yaml::to_string(&data)
-            .map_err(|e| {
-                Error::Error(format!("encoding yaml destined to {}: {}", self.output(), e))
-            })?
-            .trim()
-            .to_string();
-        bytes.extend_from_slice(y.as_bytes());
-        bytes.extend_from_slice("#\t∎".as_bytes());
+        let encoding_error = |format: &str, e: &dyn std::fmt::Display| {
+            Error::Error(format!("encoding {} destined to {}: {}", format, self.output(), e))
+        };
+        let mut bytes = Vec::<u8>::new();
+        match self.format {
+            Format::Yaml => {
+                bytes.extend_from_slice(b"\n---\n");
+                let y = serde_yaml::to_string(&data)
+                    .map_err(|e| encoding_error("yaml", &e))?
+                    .trim()
+                    .to_string();
+                bytes.extend_from_slice(y.as_bytes());
+                bytes.extend_from_slice("#\t∎".as_bytes());
+            },
+            Format::Json => {
+                bytes.extend_from_slice(if self.count == 0 { b"[\n" } else { b",\n" });
+                serde_json::to_writer(&mut bytes, &data).map_err(|e| encoding_error("json", &e))?;
+            },
+            Format::Jsonl => {
+                serde_json::to_writer(&mut bytes, &data).map_err(|e| encoding_error("json", &e))?;
+                bytes.push(b'\n');
+            },
+            Format::Csv => bytes.extend(self.row(Delimited::Csv, data)?),
+            Format::Tsv => bytes.extend(self.row(Delimited::Tsv, data)?),
+            Format::Gnu => bytes.extend(self.sum(Sums::Gnu, data)?),
+            Format::Bsd => bytes.extend(self.sum(Sums::Bsd, data)?),
+            Format::Dfxml => {
+                if self.count == 0 {
+                    bytes.extend(self.creator().header().bytes());
+                }
+                bytes.extend(dfxml::fileobject(data)?.into_bytes());
+            },
+            Format::Case => {
+                if self.count == 0 {
+                    bytes.extend(self.bundle().header().bytes());
+                }
+                bytes.extend(self.bundle().file(self.count, data)?.into_bytes());
+            },
+        }
         Ok(bytes)
     }
 
+    /// the line of a table in a checksum list, which only the tables
+    /// of files have
+    fn sum(&self, sums: Sums, data: impl Serialize) -> Result<Vec<u8>, Error> {
+        let algorithm = self.algorithm.ok_or_else(|| {
+            Error::Error(format!("writing a checksum list to {}: no algorithm", self.output()))
+        })?;
+        let document = serde_json::to_value(data)
+            .map_err(|e| Error::Error(format!("encoding a checksum line: {}", e)))?;
+        let field = |name: &str| document.get(name).and_then(|value| value.as_str());
+        match (field("filename"), field(algorithm.name())) {
+            (Some(filename), Some(digest)) =>
+                Ok(sums.line(algorithm, filename, digest)?.into_bytes()),
+            _ => Err(Error::Error(format!(
+                "only the {} digests of files can be written to a checksum list",
+                algorithm
+            ))),
+        }
+    }
+
+    /// a row of the document preceded by the header row when first
+    fn row(&self, delimited: Delimited, data: impl Serialize) -> Result<Vec<u8>, Error> {
+        let mut row = String::new();
+        if self.count == 0 {
+            row.push_str(&delimited.row(&self.columns));
+        }
+        row.push_str(&delimited.document(data, &self.columns)?);
+        Ok(row.into_bytes())
+    }
+
+    fn creator(&self) -> Creator {
+        self.creator.clone().unwrap_or_else(Creator::current)
+    }
+
+    fn bundle(&self) -> Bundle {
+        self.bundle.clone().unwrap_or_else(|| Bundle::new(Creator::current()))
+    }
+
+    /// what follows the last document, e.g.: the end of the JSON array
+    fn trailer(&self) -> Vec<u8> {
+        match self.format {
+            Format::Json if self.count == 0 => b"[]\n".to_vec(),
+            Format::Json => b"\n]\n".to_vec(),
+            Format::Dfxml if self.count == 0 => {
+                format!("{}{}", self.creator().header(), dfxml::FOOTER).into_bytes()
+            },
+            Format::Dfxml => dfxml::FOOTER.as_bytes().to_vec(),
+            Format::Case if self.count == 0 => {
+                let bundle = self.bundle();
+                format!("{}{}", bundle.header(), bundle.footer(0)).into_bytes()
+            },
+            Format::Case => self.bundle().footer(self.count).into_bytes(),
+            _ => Vec::new(),
+        }
+    }
+
     pub fn handle(&self, e: impl Into<Error>) -> Result<(), Error> {
         let e: Error = e.into();
         if self.log_err {
@@ -233,14 +962,19 @@ impl FWriter {
             if self.defer_write {
                 Err(Error::Error(format!("writing data to {}: empty buffer", self.output())))
             } else {
+                let trailer = self.trailer();
+                if !trailer.is_empty() {
+                    self.write(&trailer)?;
+                }
                 Ok(())
             }
         } else {
-            let buffer = self.buffer.clone();
+            let mut buffer = self.buffer.clone();
+            buffer.extend(self.trailer());
             self.path
                 .clone()
                 .map(|path| path.write(&buffer).map(|_| buffer.len()))
-                .unwrap_or_else(|| stdout().write(&buffer).map_err(|e| IOCoreError::from(e)))?;
+                .unwrap_or_else(|| stdout().write(&buffer).map_err(IOCoreError::from))?;
             Ok(())
         }
     }
@@ -253,23 +987,57 @@ impl FWriter {
         self.path.clone().map(|path| path.to_string()).unwrap_or("stdout".to_string())
     }
 }
+/// directory targets along with the files and directories found within
+/// them, without the queue of their [`Table`] which must not outlive
+/// the traversal
+pub type Roots = Vec<(Path, Vec<String>, Vec<String>)>;
+
+/// walk handler which feeds every file found into the queue of a
+/// [`Pool`] while the traversal is still running.
 #[derive(Clone, Debug)]
 pub struct Table {
-    opt: Cli,
+    queue: Sender<Path>,
+    seen: Arc<Mutex<BTreeSet<String>>>,
+    directories: Arc<Mutex<BTreeSet<String>>>,
+}
+
+impl Table {
+    pub fn new(queue: Sender<Path>) -> Table {
+        Table {
+            queue,
+            seen: Arc::new(Mutex::new(BTreeSet::new())),
+            directories: Arc::new(Mutex::new(BTreeSet::new())),
+        }
+    }
+
+    /// paths relative to the current directory of the files queued
+    pub fn files(&self) -> Vec<String> {
+        self.seen.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// paths relative to the current directory of the directories found
+    pub fn directories(&self) -> Vec<String> {
+        self.directories.lock().unwrap().iter().cloned().collect()
+    }
 }
 
 impl WalkProgressHandler for Table {
     fn path_matching(&mut self, location: &Path) -> Result<bool, IOCoreError> {
-        let mut writer = self.opt.writer();
-
         if location.is_file() {
-            let bytes = location.read_bytes()?;
-            let meta = (!self.opt.hexonly).then_some(Some(location.to_string())).unwrap_or(None);
-
-            let table = TableV1::new(meta.clone()).cs(bytes);
-            writer.append(&table).and(Ok(())).unwrap_or(());
+            let path = relative_to_cwd(location);
+            let unseen = self.seen.lock().unwrap().insert(path.to_string());
+            if unseen {
+                self.queue
+                    .send(path)
+                    .map_err(|e| IOCoreError::IOError(format!("queueing {}: {}", location, e)))?;
+            }
+        } else if location.is_dir() {
+            self.directories.lock().unwrap().insert(relative_to_cwd(location).to_string());
         }
-        Ok(true)
+        // nothing is aggregated by walk_dir, which would otherwise keep
+        // every path found until the traversal ends and look each one up
+        // in all those found before it
+        Ok(false)
     }
 
     fn error(&mut self, _p: &Path, _e: IOCoreError) -> Option<IOCoreError> {
@@ -277,6 +1045,61 @@ impl WalkProgressHandler for Table {
     }
 }
 
+/// bounded set of threads hashing the files pushed into its queue
+pub struct Pool {
+    queue: Sender<Path>,
+    results: Receiver<(Path, Result<TableV1, Error>)>,
+}
+
+impl Pool {
+    /// spawns `jobs` workers, which wait for their tables to be taken
+    /// once as many as there are workers are pending
+    pub fn new(jobs: usize, hexonly: bool, retries: usize, digester: &Digester) -> Pool {
+        let (queue, paths) = channel::<Path>();
+        let (sender, results) = sync_channel::<(Path, Result<TableV1, Error>)>(jobs.max(1));
+        let paths = Arc::new(Mutex::new(paths));
+        for _ in 0..jobs.max(1) {
+            let paths = paths.clone();
+            let sender = sender.clone();
+            let digester = digester.clone();
+            spawn(move || {
+                loop {
+                    let path = match paths.lock().unwrap().recv() {
+                        Ok(path) => path,
+                        Err(_) => break,
+                    };
+                    let result = hash_file(&path, hexonly, retries, &digester);
+                    if sender.send((path, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        Pool { queue, results }
+    }
+
+    pub fn queue(&self) -> Sender<Path> {
+        self.queue.clone()
+    }
+
+    /// drops the queue of the pool and yields the results of the files
+    /// pushed through it or any of its clones in the given [`Order`],
+    /// the iterator ends once every clone is dropped and every worker
+    /// is done.
+    pub fn finish(self, order: Order) -> Box<dyn Iterator<Item = (Path, Result<TableV1, Error>)>> {
+        let Pool { queue, results } = self;
+        drop(queue);
+        match order {
+            Order::Completion => Box::new(results.into_iter()),
+            Order::Sorted => {
+                let mut results = results.into_iter().collect::<Vec<_>>();
+                results.sort_by_key(|(path, _)| path.to_string());
+                Box::new(results.into_iter())
+            },
+        }
+    }
+}
+
 // pub trait HochSchreiber {
 //     fn append(&mut self, data: impl Serialize) -> Result<FWriter, Error>;
 //     fn encode(&self, data: impl Serialize) -> Result<Vec<u8>, Error>;
@@ -0,0 +1,68 @@
+use carbon14::{manifest, Algorithm, Digester, RawDigest, TableV1};
+use k9::assert_equal;
+
+/// algorithms which older versions padded to the width of another one,
+/// along with the hex digits they take
+const WIDTHS: &[(Algorithm, usize)] = &[
+    (Algorithm::Md5, 32),
+    (Algorithm::Crc16Xmodem, 4),
+    (Algorithm::Crc24Ble, 6),
+    (Algorithm::Crc24FlexrayA, 6),
+    (Algorithm::Crc24FlexrayB, 6),
+    (Algorithm::Crc24Interlaken, 6),
+    (Algorithm::Crc24LteA, 6),
+    (Algorithm::Crc24LteB, 6),
+    (Algorithm::Crc24Openpgp, 6),
+    (Algorithm::Crc24Os9, 6),
+    (Algorithm::Crc64Ms, 16),
+    (Algorithm::Crc64We, 16),
+];
+
+const MD5: &str = "25f9e794323b453885f5181f1b624d0b";
+
+/// a manifest of one table of "123456789" with the given md5 digest
+fn manifest(md5: &str) -> String {
+    format!("---\nfilename: check.txt\nsize: 9\nmd5: {}\n#\t∎", md5)
+}
+
+#[test]
+fn digests_take_as_many_digits_as_the_bits_of_their_algorithm() {
+    let digester = Digester::new(WIDTHS.iter().map(|(algorithm, _)| *algorithm).collect());
+    let table = TableV1::new(None).cs_reader(&b"123456789"[..], &digester).unwrap();
+    for (algorithm, digits) in WIDTHS {
+        let digest = table.digest(*algorithm).unwrap();
+        assert_equal!((algorithm, digest.len()), (algorithm, *digits));
+    }
+    assert_equal!(table.digest(Algorithm::Md5), Some(MD5));
+    assert_equal!(table.digest(Algorithm::Crc16Xmodem), Some("31c3"));
+    assert_equal!(table.digest(Algorithm::Crc64Ms), Some("75d4b74f024eceea"));
+    assert_equal!(table.canonical(false).unwrap(), table.clone());
+    assert_equal!(table.canonical(true).unwrap(), table);
+}
+
+#[test]
+fn unpads_digests_to_as_many_digits_as_the_bits_of_their_algorithm() {
+    for (algorithm, digits) in WIDTHS {
+        let padded = format!("{:0>64}", "1c3");
+        let raw = RawDigest::from_padded_hex(*algorithm, &padded).unwrap();
+        assert_equal!(raw.hex(), format!("{:0>digits$}", "1c3"));
+        assert!(RawDigest::from_hex(Some(*algorithm), &padded).is_err(), "{}", algorithm);
+    }
+    let raw = RawDigest::from_padded_hex(Algorithm::Crc64Ms, "4eceea").unwrap();
+    assert_equal!(raw.hex(), "00000000004eceea");
+    let raw = RawDigest::from_padded_hex(Algorithm::Crc16Xmodem, "000031c3").unwrap();
+    assert_equal!(raw.hex(), "31c3");
+    assert!(RawDigest::from_padded_hex(Algorithm::Crc16Xmodem, "0001031c3").is_err());
+}
+
+#[test]
+fn parses_md5_padded_to_64_digits_only_when_asked() {
+    let padded = manifest(&format!("{:0>64}", MD5));
+    let tables = manifest::parse(&padded, true).unwrap();
+    assert_equal!(tables.len(), 1);
+    assert_equal!(tables[0].digest(Algorithm::Md5), Some(MD5));
+    assert!(manifest::parse(&padded, false).is_err());
+
+    let tables = manifest::parse(&manifest(MD5), false).unwrap();
+    assert_equal!(tables[0].digest(Algorithm::Md5), Some(MD5));
+}
@@ -0,0 +1,422 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::process::Command;
+
+use iocore::Path;
+use k9::assert_equal;
+use serde_json::{json, Map, Value};
+
+/// the shapes of the UCO and CASE classes that --format case writes,
+/// see the `source` of the fixture
+const SHAPES: &str = include_str!("fixtures/uco-case-shapes.json");
+
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// compact IRIs of a JSON-LD `@context` or of the shapes, by prefix
+struct Prefixes(BTreeMap<String, String>);
+
+impl Prefixes {
+    fn of(context: &Value) -> Prefixes {
+        let prefixes = context
+            .as_object()
+            .expect("prefixes")
+            .iter()
+            .map(|(prefix, iri)| (prefix.clone(), iri.as_str().expect("IRI").to_string()))
+            .collect();
+        Prefixes(prefixes)
+    }
+
+    fn expand(&self, term: &str) -> String {
+        match term.split_once(':') {
+            Some((prefix, name)) if self.0.contains_key(prefix) =>
+                format!("{}{}", self.0[prefix], name),
+            _ => term.to_string(),
+        }
+    }
+}
+
+/// node shapes by class, with the superclasses of each class, every
+/// IRI expanded
+struct Shapes {
+    prefixes: Prefixes,
+    superclasses: BTreeMap<String, Vec<String>>,
+    properties: BTreeMap<String, BTreeMap<String, Value>>,
+}
+
+impl Shapes {
+    fn load() -> Shapes {
+        let shapes: Value = serde_json::from_str(SHAPES).expect("shapes");
+        let prefixes = Prefixes::of(&shapes["prefixes"]);
+        let superclasses = shapes["classes"]
+            .as_object()
+            .expect("classes")
+            .iter()
+            .map(|(class, superclasses)| {
+                let superclasses = superclasses
+                    .as_array()
+                    .expect("superclasses")
+                    .iter()
+                    .map(|superclass| prefixes.expand(superclass.as_str().expect("class")))
+                    .collect();
+                (prefixes.expand(class), superclasses)
+            })
+            .collect();
+        let properties = shapes["shapes"]
+            .as_object()
+            .expect("shapes")
+            .iter()
+            .map(|(class, properties)| {
+                let properties = properties
+                    .as_object()
+                    .expect("properties")
+                    .iter()
+                    .map(|(property, shape)| (prefixes.expand(property), shape.clone()))
+                    .collect();
+                (prefixes.expand(class), properties)
+            })
+            .collect();
+        Shapes {
+            prefixes,
+            superclasses,
+            properties,
+        }
+    }
+
+    /// the classes and their superclasses, the classes the shapes
+    /// don't define aside
+    fn ancestors(&self, classes: &[String]) -> BTreeSet<String> {
+        let mut ancestors = BTreeSet::new();
+        let mut pending = classes.to_vec();
+        while let Some(class) = pending.pop() {
+            if let Some(superclasses) = self.superclasses.get(&class) {
+                pending.extend(superclasses.iter().cloned());
+                ancestors.insert(class);
+            }
+        }
+        ancestors
+    }
+}
+
+/// the nodes of a JSON-LD document, i.e.: the typed objects of its
+/// graph other than literals, nested ones included
+fn nodes<'a>(value: &'a Value, nodes: &mut Vec<&'a Map<String, Value>>) {
+    match value {
+        Value::Object(object) => {
+            if object.contains_key("@type") && !object.contains_key("@value") {
+                nodes.push(object);
+            }
+            object.values().for_each(|value| self::nodes(value, nodes));
+        },
+        Value::Array(values) => values.iter().for_each(|value| self::nodes(value, nodes)),
+        _ => {},
+    }
+}
+
+fn values(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    }
+}
+
+/// whether `lexical` is in the lexical space of the XSD `datatype`
+fn lexical(datatype: &str, lexical: &str) -> bool {
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    match datatype.strip_prefix(XSD) {
+        Some("integer") => digits(lexical.strip_prefix(['-', '+']).unwrap_or(lexical)),
+        Some("nonNegativeInteger") => digits(lexical.strip_prefix('+').unwrap_or(lexical)),
+        Some("boolean") => ["true", "false", "1", "0"].contains(&lexical),
+        Some("hexBinary") =>
+            lexical.len() % 2 == 0 && lexical.bytes().all(|b| b.is_ascii_hexdigit()),
+        Some("dateTime") => {
+            let Some((date, time)) = lexical.split_once('T') else { return false };
+            let date = date.split('-').collect::<Vec<_>>();
+            let zone = time.find(['Z', '+', '-']).unwrap_or(time.len());
+            let (time, zone) = time.split_at(zone);
+            let (time, fraction) = time.split_once('.').unwrap_or((time, "0"));
+            let time = time.split(':').collect::<Vec<_>>();
+            let zone = match zone {
+                "" | "Z" => true,
+                zone => {
+                    let zone = zone[1..].split(':').collect::<Vec<_>>();
+                    zone.len() == 2 && zone.iter().all(|part| part.len() == 2 && digits(part))
+                },
+            };
+            date.len() == 3
+                && date[0].len() >= 4
+                && date[1..].iter().all(|part| part.len() == 2)
+                && date.iter().all(|part| digits(part))
+                && time.len() == 3
+                && time.iter().all(|part| part.len() == 2 && digits(part))
+                && digits(fraction)
+                && zone
+        },
+        _ => true,
+    }
+}
+
+/// checks the documents written by --format case against [`Shapes`],
+/// as SHACL would with `sh:class`, `sh:datatype`, `sh:in`, `sh:or`,
+/// `sh:minCount` and `sh:maxCount`, and as `case_validate` would with
+/// the classes and properties the ontologies don't define
+struct Validator<'a> {
+    shapes: &'a Shapes,
+    prefixes: Prefixes,
+    nodes: BTreeMap<String, &'a Map<String, Value>>,
+    errors: Vec<String>,
+}
+
+impl<'a> Validator<'a> {
+    fn validate(shapes: &'a Shapes, document: &'a Value) -> Vec<String> {
+        let prefixes = Prefixes::of(&document["@context"]);
+        let mut graph = Vec::new();
+        nodes(&document["@graph"], &mut graph);
+        let mut validator = Validator {
+            shapes,
+            nodes: BTreeMap::new(),
+            prefixes,
+            errors: Vec::new(),
+        };
+        for node in &graph {
+            match node.get("@id").and_then(Value::as_str) {
+                Some(id) if !id.starts_with("_:") => {
+                    let id = validator.prefixes.expand(id);
+                    if validator.nodes.insert(id.clone(), node).is_some() {
+                        validator.errors.push(format!("{} is the @id of several nodes", id));
+                    }
+                },
+                _ => {
+                    let node = Value::Object((*node).clone());
+                    validator.errors.push(format!("{} has no IRI", node));
+                },
+            }
+        }
+        for node in graph {
+            validator.node(node);
+        }
+        validator.errors
+    }
+
+    fn types(&self, node: &Map<String, Value>) -> Vec<String> {
+        node.get("@type")
+            .map(values)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(Value::as_str)
+            .map(|class| self.prefixes.expand(class))
+            .collect()
+    }
+
+    fn node(&mut self, node: &Map<String, Value>) {
+        let id = node.get("@id").and_then(Value::as_str).unwrap_or("?").to_string();
+        let types = self.types(node);
+        let classes = self.shapes.ancestors(&types);
+        for class in &types {
+            if !classes.contains(class) {
+                self.errors.push(format!("{}: undefined class {}", id, class));
+            }
+        }
+        let shapes = classes
+            .iter()
+            .filter_map(|class| self.shapes.properties.get(class))
+            .flat_map(|properties| properties.iter())
+            .collect::<BTreeMap<_, _>>();
+        for (property, shape) in &shapes {
+            let count = node
+                .iter()
+                .filter(|(key, _)| self.prefixes.expand(key) == **property)
+                .map(|(_, value)| values(value).len())
+                .sum::<usize>();
+            let min = shape.get("minCount").and_then(Value::as_u64).unwrap_or(0) as usize;
+            let max = shape.get("maxCount").and_then(Value::as_u64).map(|max| max as usize);
+            if count < min || max.is_some_and(|max| count > max) {
+                self.errors.push(format!("{}: {} values of {}", id, count, property));
+            }
+        }
+        for (key, value) in node {
+            if key.starts_with('@') {
+                continue;
+            }
+            let property = self.prefixes.expand(key);
+            let Some(shape) = shapes.get(&property) else {
+                self.errors.push(format!("{}: undefined property {}", id, property));
+                continue;
+            };
+            for value in values(value) {
+                if let Err(error) = self.value(shape, value) {
+                    self.errors.push(format!("{}: {} {}", id, property, error));
+                }
+            }
+        }
+    }
+
+    fn value(&self, shape: &Value, value: &Value) -> Result<(), String> {
+        if let Some(alternatives) = shape.get("or").and_then(Value::as_array) {
+            let mut errors = Vec::new();
+            for alternative in alternatives {
+                match self.value(alternative, value) {
+                    Ok(()) => return Ok(()),
+                    Err(error) => errors.push(error),
+                }
+            }
+            return Err(errors.join(", nor "));
+        }
+        if let Some(datatype) = shape.get("datatype").and_then(Value::as_str) {
+            let datatype = self.shapes.prefixes.expand(datatype);
+            let (actual, literal) = match value {
+                Value::String(literal) => (format!("{}string", XSD), literal.as_str()),
+                Value::Object(object) => (
+                    object
+                        .get("@type")
+                        .and_then(Value::as_str)
+                        .map(|datatype| self.prefixes.expand(datatype))
+                        .unwrap_or(format!("{}string", XSD)),
+                    object.get("@value").and_then(Value::as_str).ok_or("is not a literal")?,
+                ),
+                value => return Err(format!("{} is not a typed literal", value)),
+            };
+            if actual != datatype {
+                return Err(format!("{:#?} is a {}, not a {}", literal, actual, datatype));
+            }
+            if !lexical(&datatype, literal) {
+                return Err(format!("{:#?} is not a lexical {}", literal, datatype));
+            }
+            if let Some(allowed) = shape.get("in").and_then(Value::as_array) {
+                if !allowed.contains(&json!(literal)) {
+                    return Err(format!("{:#?} is not one of {}", literal, json!(allowed)));
+                }
+            }
+        }
+        if let Some(class) = shape.get("class").and_then(Value::as_str) {
+            let class = self.shapes.prefixes.expand(class);
+            let id = value.get("@id").and_then(Value::as_str).ok_or("is not a node")?;
+            let node = self
+                .nodes
+                .get(&self.prefixes.expand(id))
+                .ok_or(format!("refers to {} which is not in the graph", id))?;
+            if !self.shapes.ancestors(&self.types(node)).contains(&class) {
+                return Err(format!("refers to {} which is not a {}", id, class));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// runs carbon14 in `directory`, returning what it wrote to a file
+fn carbon14(directory: &Path, arguments: &[&str]) -> String {
+    let output = Path::tmp_file();
+    let status = Command::new(env!("CARGO_BIN_EXE_carbon14"))
+        .args(arguments)
+        .args(["-o", "-f", &output.to_string()])
+        .current_dir(directory.to_string())
+        .status()
+        .expect("running carbon14");
+    assert!(status.success(), "carbon14 {} exited with {}", arguments.join(" "), status);
+    let written = output.read().expect("reading the output of carbon14");
+    output.delete().expect("removing the output of carbon14");
+    written
+}
+
+fn count(nodes: &[&Map<String, Value>], class: &str) -> usize {
+    nodes.iter().filter(|node| node["@type"] == json!(class)).count()
+}
+
+#[test]
+fn writes_files_hashes_and_an_action_the_shapes_accept() {
+    let directory = Path::tmp();
+    std::fs::write(format!("{}/hello", directory), b"hello\n").unwrap();
+    std::fs::write(format!("{}/empty", directory), b"").unwrap();
+    std::fs::create_dir(format!("{}/nested", directory)).unwrap();
+    std::fs::write(format!("{}/nested/abc", directory), b"abc").unwrap();
+    let written = carbon14(
+        &directory,
+        &["-a", "md5,sha256,sha3_256,blake3,crc32_iscsi,ssdeep", "--format", "case", "."],
+    );
+    directory.delete().unwrap();
+
+    let document: Value = serde_json::from_str(&written).expect("a JSON-LD document");
+    assert_equal!(Validator::validate(&Shapes::load(), &document), Vec::<String>::new());
+
+    let mut graph = Vec::new();
+    nodes(&document["@graph"], &mut graph);
+    assert_equal!(count(&graph, "uco-tool:Tool"), 1);
+    assert_equal!(count(&graph, "uco-observable:File"), 3);
+    assert_equal!(count(&graph, "uco-observable:FileFacet"), 3);
+    assert_equal!(count(&graph, "uco-observable:ContentDataFacet"), 3);
+    assert_equal!(count(&graph, "uco-types:Hash"), 3 * 5);
+    assert_equal!(count(&graph, "case-investigation:InvestigativeAction"), 1);
+
+    let action = graph
+        .iter()
+        .find(|node| node["@type"] == json!("case-investigation:InvestigativeAction"))
+        .unwrap();
+    let results = values(&action["uco-action:result"]).len();
+    assert_equal!(results, 3);
+
+    let facet = graph
+        .iter()
+        .find(|node| node.get("uco-observable:filePath") == Some(&json!("hello")))
+        .expect("the FileFacet of hello");
+    assert_equal!(facet["uco-observable:fileName"], json!("hello"));
+    let hashes = graph
+        .iter()
+        .filter(|node| node["@type"] == json!("uco-types:Hash"))
+        .map(|hash| {
+            let method = hash["uco-types:hashMethod"].clone();
+            (method, hash["uco-types:hashValue"]["@value"].clone())
+        })
+        .collect::<Vec<_>>();
+    let sha256 = json!({ "@type": "uco-vocabulary:HashNameVocab", "@value": "SHA256" });
+    assert!(hashes.contains(&(
+        sha256,
+        json!("5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03")
+    )));
+    assert!(hashes.contains(&(json!("crc32_iscsi"), json!("00000000"))));
+}
+
+#[test]
+fn the_validator_refuses_what_the_shapes_do_not_allow() {
+    let document = json!({
+        "@context": {
+            "kb": "http://example.org/kb/",
+            "case-investigation": "https://ontology.caseontology.org/case/investigation/",
+            "uco-action": "https://ontology.unifiedcyberontology.org/uco/action/",
+            "uco-core": "https://ontology.unifiedcyberontology.org/uco/core/",
+            "uco-observable": "https://ontology.unifiedcyberontology.org/uco/observable/",
+            "uco-types": "https://ontology.unifiedcyberontology.org/uco/types/",
+            "xsd": "http://www.w3.org/2001/XMLSchema#",
+        },
+        "@graph": [
+            {
+                "@id": "kb:file",
+                "@type": "uco-observable:File",
+                "uco-observable:sha256": "00",
+                "uco-core:hasFacet": [{
+                    "@id": "kb:content",
+                    "@type": "uco-observable:ContentDataFacet",
+                    "uco-observable:hash": [{
+                        "@id": "kb:hash",
+                        "@type": "uco-types:Hash",
+                        "uco-types:hashValue": { "@type": "xsd:string", "@value": "00" },
+                    }],
+                }],
+            },
+            {
+                "@id": "kb:action",
+                "@type": "case-investigation:InvestigativeAction",
+                "uco-action:startTime": { "@type": "xsd:dateTime", "@value": "yesterday" },
+                "uco-action:result": [{ "@id": "kb:nothing" }],
+            },
+        ],
+    });
+    let errors = Validator::validate(&Shapes::load(), &document);
+    assert_eq!(errors.len(), 5, "{:#?}", errors);
+    for error in [
+        "undefined property https://ontology.unifiedcyberontology.org/uco/observable/sha256",
+        "0 values of https://ontology.unifiedcyberontology.org/uco/types/hashMethod",
+        "\"00\" is a http://www.w3.org/2001/XMLSchema#string, not a http://www.w3.org/2001/XMLSchema#hexBinary",
+        "\"yesterday\" is not a lexical http://www.w3.org/2001/XMLSchema#dateTime",
+        "refers to kb:nothing which is not in the graph",
+    ] {
+        assert!(errors.iter().any(|e| e.contains(error)), "{} not in {:#?}", error, errors);
+    }
+}
@@ -0,0 +1,147 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use carbon14::table::CHUNK_SIZE;
+use carbon14::{Algorithm, Checkpoint, Digester, TableV1};
+use iocore::Path;
+use k9::assert_equal;
+
+/// data that fails to be read past `stop`, as if the run was
+/// interrupted there
+struct Interrupted {
+    data: Cursor<Vec<u8>>,
+    stop: u64,
+}
+
+impl Read for Interrupted {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let position = self.data.position();
+        if position >= self.stop {
+            return Err(std::io::Error::other("interrupted"));
+        }
+        let length = buffer.len().min((self.stop - position) as usize);
+        self.data.read(&mut buffer[..length])
+    }
+}
+
+impl Seek for Interrupted {
+    fn seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        self.data.seek(position)
+    }
+}
+
+fn data(length: usize) -> Vec<u8> {
+    (0..length).map(|index| (index * 31 + (index >> 8)) as u8).collect()
+}
+
+/// writes the data to a file of the given name within the directory
+fn written(directory: &Path, name: &str, data: &[u8]) -> Path {
+    let file = Path::raw(format!("{}/{}", directory, name));
+    file.write(data).unwrap();
+    file
+}
+
+fn resumable() -> Vec<Algorithm> {
+    Algorithm::every().filter(Algorithm::resumable).collect()
+}
+
+/// digests `length` bytes interrupted at `stop`, resumes from the
+/// checkpoint saved at the last chunk read and checks the digests
+/// against those of the hashers of [`TableV1::cs_reader`]
+fn resumed_equals_uninterrupted(length: usize, stop: usize) {
+    let algorithms = resumable();
+    let digester = Digester::new(algorithms.clone());
+    let data = data(length);
+    let expected = TableV1::new(None).cs_reader(&data[..], &digester).unwrap();
+
+    let directory = Path::tmp();
+    let file = written(&directory, "data", &data);
+    let path = Path::raw(format!("{}/checkpoint.yaml", directory));
+    let interrupted = Interrupted {
+        data: Cursor::new(data.clone()),
+        stop: stop as u64,
+    };
+    let result = TableV1::new(None).cs_resumable(interrupted, &digester, &file, &path, 1, None);
+    assert!(result.is_err(), "{} bytes were not interrupted at {}", length, stop);
+    let checkpoint = Checkpoint::read(&path).unwrap();
+    assert_equal!(checkpoint.offset, (stop - stop % CHUNK_SIZE) as u64);
+
+    let actual = TableV1::new(None)
+        .cs_resumable(Cursor::new(data), &digester, &file, &path, 1, Some(checkpoint))
+        .unwrap();
+    assert!(!path.exists());
+    directory.delete().unwrap();
+    for algorithm in algorithms {
+        assert_equal!(
+            (algorithm, length, actual.digest(algorithm)),
+            (algorithm, length, expected.digest(algorithm))
+        );
+    }
+}
+
+/// lengths of the last block of each resumable algorithm before and
+/// after it has room for the padding, i.e.: the 64 byte blocks of MD5,
+/// SHA-1 and SHA-256, the 128 byte blocks of SHA-512 and the rates of
+/// the sponges of SHA-3, Keccak and SHAKE
+const TAILS: &[usize] = &[
+    0, 1, 55, 56, 63, 64, 71, 72, 103, 104, 111, 112, 127, 128, 135, 136, 143, 144, 167, 168,
+];
+
+#[test]
+fn resumes_at_padding_boundaries_past_a_chunk() {
+    for tail in TAILS {
+        resumed_equals_uninterrupted(CHUNK_SIZE + tail, CHUNK_SIZE + tail / 2);
+    }
+}
+
+#[test]
+fn resumes_at_chunk_boundaries() {
+    resumed_equals_uninterrupted(CHUNK_SIZE, CHUNK_SIZE);
+    resumed_equals_uninterrupted(CHUNK_SIZE * 2, CHUNK_SIZE);
+    resumed_equals_uninterrupted(CHUNK_SIZE * 2, CHUNK_SIZE * 2);
+    resumed_equals_uninterrupted(CHUNK_SIZE * 3 + 1, CHUNK_SIZE * 2 + 1);
+}
+
+#[test]
+fn digests_without_a_checkpoint_as_the_hashers_do() {
+    let algorithms = resumable();
+    let digester = Digester::new(algorithms.clone());
+    for tail in TAILS {
+        let data = data(CHUNK_SIZE + tail);
+        let expected = TableV1::new(None).cs_reader(&data[..], &digester).unwrap();
+        let directory = Path::tmp();
+        let file = written(&directory, "data", &data);
+        let path = Path::raw(format!("{}/checkpoint.yaml", directory));
+        let actual = TableV1::new(None)
+            .cs_resumable(Cursor::new(data), &digester, &file, &path, 1, None)
+            .unwrap();
+        directory.delete().unwrap();
+        for algorithm in &algorithms {
+            assert_equal!(actual.digest(*algorithm), expected.digest(*algorithm));
+        }
+    }
+}
+
+#[test]
+fn refuses_the_checkpoint_of_another_file_of_the_same_size() {
+    let digester = Digester::new(vec![Algorithm::Sha256, Algorithm::Md5]);
+    let data = data(CHUNK_SIZE * 2);
+    let directory = Path::tmp();
+    let file = written(&directory, "data", &data);
+    let other = written(&directory, "other", &data.iter().map(|byte| !byte).collect::<Vec<_>>());
+    let path = Path::raw(format!("{}/checkpoint.yaml", directory));
+    let interrupted = Interrupted {
+        data: Cursor::new(data.clone()),
+        stop: CHUNK_SIZE as u64,
+    };
+    let result = TableV1::new(None).cs_resumable(interrupted, &digester, &file, &path, 1, None);
+    assert!(result.is_err());
+    let checkpoint = Checkpoint::read(&path).unwrap();
+    assert_equal!(checkpoint.offset, CHUNK_SIZE as u64);
+
+    let reader = Cursor::new(other.read_bytes().unwrap());
+    let result =
+        TableV1::new(None).cs_resumable(reader, &digester, &other, &path, 1, Some(checkpoint));
+    directory.delete().unwrap();
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("as it was then"), "{}", error);
+}